@@ -1,60 +1,6 @@
-use rand::seq::IteratorRandom; // For randomly choosing elements from iterators
+use algorithmics::graph::min_cut;
 use std::collections::HashMap; // For representing the graph as an adjacency map
 
-/// Perform one run of Karger’s contraction algorithm on the given graph.
-/// The graph is represented as an adjacency map: each node → a Vec of its neighbors.
-///
-/// Returns the size of the cut found (i.e. the number of edges crossing when only two “super-nodes” remain).
-fn karger_contraction(adj: &HashMap<u32, Vec<u32>>) -> usize {
-    // Make a working copy of the adjacency map so we can mutate it
-    let mut adj = adj.clone();
-    let mut rng = rand::rng();
-
-    // While more than 2 super-nodes remain, keep contracting
-    while adj.len() > 2 {
-        // Pick a random edge (u, v):
-        // 1. Randomly pick a node u from the keys
-        // 2. Randomly pick a neighbor v of u
-        let (&u, nbrs) = adj.iter().choose(&mut rng).unwrap();
-        let &v = nbrs.iter().choose(&mut rng).unwrap();
-
-        // Merge v into u:
-        // 1) Append v’s adjacency list onto u’s adjacency list
-        let v_nbrs = adj.remove(&v).unwrap(); // Remove v and get its neighbors
-        let u_nbrs = adj.get_mut(&u).unwrap();
-        u_nbrs.extend(v_nbrs.iter().cloned());
-
-        // 2) For every neighbor w of v, replace occurrences of v with u in their adjacency lists
-        for w in v_nbrs {
-            if let Some(w_nbrs) = adj.get_mut(&w) {
-                for x in w_nbrs.iter_mut() {
-                    if *x == v {
-                        *x = u; // Replace v with u
-                    }
-                }
-            }
-        }
-
-        // 3) Remove self‐loops at u (edges from u to itself)
-        adj.get_mut(&u)
-           .unwrap()
-           .retain(|&x| x != u);
-    }
-
-    // Now exactly two nodes remain; pick one and return its number of edges (the cut size)
-    let (_, nbrs) = adj.iter().next().unwrap();
-    nbrs.len()
-}
-
-/// Run Karger’s algorithm `reps` times and return the smallest cut found.
-/// More repetitions increase the probability of finding the true min-cut.
-fn min_cut(adj: &HashMap<u32, Vec<u32>>, reps: usize) -> usize {
-    (0..reps)
-        .map(|_| karger_contraction(adj)) // Run the contraction algorithm multiple times
-        .min() // Take the smallest cut found
-        .unwrap()
-}
-
 fn main() {
     // Number of repetitions; more repetitions → higher probability of finding true min-cut
     const REPS: usize = 50;
@@ -79,7 +25,7 @@ fn main() {
         g3.entry(u).or_default();
     }
     // Add triangle edges for first triangle
-    for &(u, v) in &[(1,2),(2,3),(3,1)] {
+    for &(u, v) in &[(1, 2), (2, 3), (3, 1)] {
         g3.get_mut(&u).unwrap().push(v);
         g3.get_mut(&v).unwrap().push(u);
     }
@@ -88,7 +34,7 @@ fn main() {
         g3.entry(u).or_default();
     }
     // Add triangle edges for second triangle
-    for &(u, v) in &[(4,5),(5,6),(6,4)] {
+    for &(u, v) in &[(4, 5), (5, 6), (6, 4)] {
         g3.get_mut(&u).unwrap().push(v);
         g3.get_mut(&v).unwrap().push(u);
     }
@@ -105,7 +51,7 @@ fn main() {
 
     // Run Karger's algorithm on each test graph and print the estimated min-cut
     for (name, graph) in graphs {
-        let cut = min_cut(&graph, REPS);
+        let cut = min_cut(&graph, REPS, &mut rand::rng()).expect("Niepoprawny graf testowy");
         println!("{} → estimated min-cut = {}", name, cut);
     }
 }
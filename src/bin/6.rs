@@ -1,11 +1,4 @@
-fn horner(coeffs: &[f64], x: f64) -> f64 {
-    let mut result = 0.0;
-    // Iterujemy po współczynnikach w kolejności od najwyższej potęgi do wyrazu wolnego.
-    for &a in coeffs.iter().rev() {
-        result = a + x * result;
-    }
-    result
-}
+use algorithmics::poly::horner;
 
 fn main() {
     // Przykładowy wielomian: 2 + 3*x + 4*x^2
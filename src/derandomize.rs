@@ -0,0 +1,53 @@
+//! Derandomization by the method of conditional expectations on bit strings.
+
+use rand::Rng;
+
+use crate::{Error, Result};
+
+/// Very bad random‐bit generator: X[i] ← random bit, for a string of length `n`
+pub fn random_bits<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Vec<u8> {
+    // For each bit in the string, set it to 1 or 0 randomly
+    (0..n).map(|_| if rng.random() { 1 } else { 0 }).collect()
+}
+
+/// “Success” = all‐zero string
+pub fn is_success(x: &[u8]) -> bool {
+    // Return true if all bits are zero, false otherwise
+    x.iter().all(|&b| b == 0)
+}
+
+/// Conditional‐expectation function
+/// c(prefix) = Pr[success | we have already fixed prefix]
+///
+/// Returns [`Error::InvalidParameter`] if the prefix is longer than `total_len`.
+pub fn cond_exp(prefix: &[u8], total_len: usize) -> Result<f64> {
+    if prefix.len() > total_len {
+        return Err(Error::InvalidParameter("prefiks dłuższy niż total_len"));
+    }
+    // If any bit in the prefix is 1, success is impossible
+    if prefix.contains(&1) {
+        Ok(0.0)
+    } else {
+        // Otherwise, probability is 2^-(remaining bits)
+        Ok(2f64.powi(-((total_len - prefix.len()) as i32)))
+    }
+}
+
+/// Derandomize by fixing bits one by one to maximize c(...)
+pub fn derandomized_bits(n: usize) -> Vec<u8> {
+    let mut x = Vec::with_capacity(n); // Will build the bit string one bit at a time
+    for _ in 0..n {
+        // Try setting the next bit to 0
+        x.push(0);
+        // The prefix never exceeds n, so cond_exp cannot fail here
+        let c0 = cond_exp(&x, n).unwrap_or(0.0); // Conditional expectation if bit is 0
+        *x.last_mut().unwrap() = 1;
+        let c1 = cond_exp(&x, n).unwrap_or(0.0); // Conditional expectation if bit is 1
+        // Pick the value (0 or 1) that gives higher probability of success
+        // If tied, prefer 0
+        if c1 <= c0 {
+            *x.last_mut().unwrap() = 0; // set back to 0
+        }
+    }
+    x
+}
@@ -0,0 +1,117 @@
+use crate::{Error, Result};
+
+/// Nukleotyd DNA.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Nucleotide {
+    A,
+    T,
+    G,
+    C,
+}
+
+impl Nucleotide {
+    /// Symbol nukleotydu jako znak (`'A'`, `'T'`, `'G'` lub `'C'`).
+    pub fn to_char(self) -> char {
+        match self {
+            Nucleotide::A => 'A',
+            Nucleotide::T => 'T',
+            Nucleotide::G => 'G',
+            Nucleotide::C => 'C',
+        }
+    }
+}
+
+/// Kod ASCII symbolu nukleotydu, np. do indeksowania sekwencji jako bajtów.
+impl From<Nucleotide> for u8 {
+    fn from(n: Nucleotide) -> u8 {
+        n.to_char() as u8
+    }
+}
+
+// Implementacja konwersji z char na Nucleotide.
+impl TryFrom<char> for Nucleotide {
+    type Error = Error;
+    fn try_from(ch: char) -> Result<Self> {
+        match ch {
+            'A' => Ok(Nucleotide::A),
+            'T' => Ok(Nucleotide::T),
+            'G' => Ok(Nucleotide::G),
+            'C' => Ok(Nucleotide::C),
+            _ => Err(Error::InvalidSymbol(ch)),
+        }
+    }
+}
+
+/// Konwertuje ciąg `&str` na wektor nukleotydów.
+///
+/// Zwraca [`Error::InvalidSymbol`] dla pierwszego znaku spoza `A`, `T`, `G`, `C`.
+pub fn parse_sequence(seq: &str) -> Result<Vec<Nucleotide>> {
+    seq.chars().map(Nucleotide::try_from).collect()
+}
+
+/// Czy `slice` to kodon startowy `ATG`.
+pub fn is_start_codon(slice: &[Nucleotide]) -> bool {
+    slice == [Nucleotide::A, Nucleotide::T, Nucleotide::G]
+}
+
+/// Czy `slice` to jeden z kodonów stop: `TAA`, `TAG`, `TGA`.
+pub fn is_stop_codon(slice: &[Nucleotide]) -> bool {
+    slice == [Nucleotide::T, Nucleotide::A, Nucleotide::A]
+        || slice == [Nucleotide::T, Nucleotide::A, Nucleotide::G]
+        || slice == [Nucleotide::T, Nucleotide::G, Nucleotide::A]
+}
+
+/// Czy `slice` to kodon zakazany wewnątrz genu, czyli jeden z:
+/// "ATG", "TAA", "TAG", "TGA".
+pub fn is_forbidden_codon(slice: &[Nucleotide]) -> bool {
+    is_start_codon(slice) || is_stop_codon(slice)
+}
+
+/// Przeszukuje ciąg w poszukiwaniu fragmentów genu o postaci:
+/// "ATG" + u + F, gdzie F to kodon stop, |u| >= 30, a w u nie występują
+/// żadne kodony z { "ATG", "TAA", "TAG", "TGA" }.
+///
+/// Zwraca pary (indeks początkowy, indeks końcowy), gdzie gen to `seq[start..end]`.
+pub fn find_genes(seq: &[Nucleotide]) -> Vec<(usize, usize)> {
+    let n = seq.len();
+    let mut genes = Vec::new();
+    // Krótszy ciąg nie zawiera nawet jednego kodonu.
+    if n < 3 {
+        return genes;
+    }
+    // Tablica, która dla każdej pozycji i (gdzie może zaczynać się kodon) wskaże
+    // pierwsze wystąpienie zakazanego kodonu od pozycji i.
+    let mut next_forbidden = vec![n; n];
+
+    // Przetwarzamy od końca – tylko dla pozycji, gdzie mamy pełny kodon (do n-3).
+    for i in (0..=n - 3).rev() {
+        if is_forbidden_codon(&seq[i..i + 3]) {
+            next_forbidden[i] = i;
+        } else if i + 1 < n {
+            next_forbidden[i] = next_forbidden[i + 1];
+        }
+    }
+
+    // Przeszukujemy ciąg w poszukiwaniu startowego kodonu "ATG".
+    for i in 0..=n - 3 {
+        if is_start_codon(&seq[i..i + 3]) {
+            let u_start = i + 3;
+            if u_start >= n {
+                continue;
+            }
+            // j – pozycja pierwszego wystąpienia zakazanego kodonu po u_start.
+            let j = next_forbidden[u_start];
+            // Sprawdzamy, czy znaleziono zakazany kodon, u jest wystarczająco długie
+            // oraz czy zakazany kodon jest jednym ze stop kodonów.
+            if j < n && j >= u_start + 30 && j + 3 <= n && is_stop_codon(&seq[j..j + 3]) {
+                genes.push((i, j + 3));
+            }
+        }
+    }
+    genes
+}
+
+/// Konwertuje fragment wektora nukleotydów na `String`.
+pub fn nucleotides_to_string(slice: &[Nucleotide]) -> String {
+    slice.iter().map(|n| n.to_char()).collect()
+}
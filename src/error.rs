@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// Błędy zwracane przez funkcje biblioteki.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Symbol spoza dopuszczalnego alfabetu (np. nukleotyd inny niż A, T, G, C).
+    InvalidSymbol(char),
+    /// Niepoprawna wartość parametru; opis wskazuje, którego.
+    InvalidParameter(&'static str),
+    /// Wierzchołek o numerze `vertex` nie istnieje w grafie o `n` wierzchołkach.
+    VertexOutOfRange { vertex: usize, n: usize },
+    /// Graf nie spełnia założeń algorytmu; opis wskazuje, których.
+    InvalidGraph(&'static str),
+    /// Błąd składni wyrażenia regularnego na pozycji `position` (w bajtach).
+    InvalidRegex {
+        position: usize,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidSymbol(ch) => write!(f, "Niepoprawny symbol: {}", ch),
+            Error::InvalidParameter(what) => write!(f, "Niepoprawny parametr: {}", what),
+            Error::VertexOutOfRange { vertex, n } => {
+                write!(f, "Wierzchołek {} poza zakresem 0..{}", vertex, n)
+            }
+            Error::InvalidGraph(what) => write!(f, "Niepoprawny graf: {}", what),
+            Error::InvalidRegex { position, reason } => write!(
+                f,
+                "Niepoprawne wyrażenie regularne na pozycji {}: {}",
+                position, reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Skrót dla `Result` z błędem [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,224 @@
+//! Randomized graph cuts: max-cut (random and derandomized) and Karger's min-cut.
+
+use rand::Rng;
+use rand::seq::IteratorRandom; // For randomly choosing elements from iterators
+use std::collections::HashMap; // For representing the graph as an adjacency map
+
+use crate::{Error, Result};
+
+/// Simple undirected graph in adjacency‐list form.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    n: usize,                   // Number of vertices
+    adj: Vec<Vec<usize>>,       // Adjacency list: adj[v] = neighbors of v
+    edges: Vec<(usize, usize)>, // List of edges (u, v)
+}
+
+impl Graph {
+    /// Create an empty graph on n vertices.
+    pub fn new(n: usize) -> Self {
+        Graph {
+            n,
+            adj: vec![Vec::new(); n],
+            edges: Vec::new(),
+        }
+    }
+
+    /// Number of vertices.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// List of edges (u, v) in insertion order.
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Neighbors of vertex `v`.
+    ///
+    /// Panics if `v` is not a vertex of the graph.
+    pub fn neighbors(&self, v: usize) -> &[usize] {
+        &self.adj[v]
+    }
+
+    /// Add an undirected edge (u,v). No checks for duplicates.
+    ///
+    /// Returns [`Error::VertexOutOfRange`] if either endpoint is not a vertex.
+    pub fn add_edge(&mut self, u: usize, v: usize) -> Result<()> {
+        for vertex in [u, v] {
+            if vertex >= self.n {
+                return Err(Error::VertexOutOfRange { vertex, n: self.n });
+            }
+        }
+        self.adj[u].push(v); // Add v to u's adjacency list
+        self.adj[v].push(u); // Add u to v's adjacency list
+        self.edges.push((u, v)); // Store the edge in the edge list
+        Ok(())
+    }
+
+    /// Count how many edges cross the cut defined by `side[v]` in {false,true}.
+    /// An edge (u, v) crosses the cut if side[u] != side[v].
+    ///
+    /// Returns [`Error::InvalidParameter`] if `side` does not have one entry per vertex.
+    pub fn cut_size(&self, side: &[bool]) -> Result<usize> {
+        if side.len() != self.n {
+            return Err(Error::InvalidParameter(
+                "side musi mieć po jednym wpisie na wierzchołek",
+            ));
+        }
+        Ok(self
+            .edges
+            .iter()
+            .filter(|&&(u, v)| side[u] != side[v])
+            .count())
+    }
+
+    /// Randomized max‐cut: assign each vertex to side = random<bool>().
+    /// Returns the side assignment and the cut size.
+    pub fn random_cut<R: Rng + ?Sized>(&self, rng: &mut R) -> (Vec<bool>, usize) {
+        // Randomly assign each vertex to true or false
+        let side: Vec<bool> = (0..self.n).map(|_| rng.random()).collect();
+        let c = self.count_crossing(&side); // Compute the cut size
+        (side, c)
+    }
+
+    /// Derandomize via conditional expectations to guarantee cut ≥ |E|/2.
+    ///
+    /// Assign vertices in order 0,1,…,n-1. At each step, choose the side
+    /// (true or false) that maximizes the number of new crossing edges
+    /// to already-assigned neighbors. This ensures the expected cut size
+    /// is at least half the number of edges.
+    pub fn derandomized_cut(&self) -> (Vec<bool>, usize) {
+        let mut side = vec![false; self.n]; // Side assignment for each vertex
+        let mut assigned = vec![false; self.n]; // Track which vertices are assigned
+
+        // Track for each vertex the number of neighbors already assigned to 'true'
+        let mut true_nbrs = vec![0usize; self.n];
+        // and to 'false'
+        let mut false_nbrs = vec![0usize; self.n];
+
+        for v in 0..self.n {
+            // For vertex v, compute the number of crossing edges if v is assigned false or true
+            // If v=false, edges crossing to neighbors in true_nbrs[v]
+            // If v=true, edges crossing to neighbors in false_nbrs[v]
+            let cross_if_false = true_nbrs[v];
+            let cross_if_true = false_nbrs[v];
+
+            // Assign v to the side that maximizes the number of new crossing edges
+            side[v] = cross_if_true > cross_if_false;
+            assigned[v] = true;
+
+            // Update the neighbor counts for unassigned neighbors
+            for &w in &self.adj[v] {
+                if !assigned[w] {
+                    if side[v] {
+                        true_nbrs[w] += 1;
+                    } else {
+                        false_nbrs[w] += 1;
+                    }
+                }
+            }
+        }
+
+        let c = self.count_crossing(&side); // Compute the final cut size
+        (side, c)
+    }
+
+    /// Cut size for a `side` vector already known to have length `n`.
+    fn count_crossing(&self, side: &[bool]) -> usize {
+        self.edges
+            .iter()
+            .filter(|&&(u, v)| side[u] != side[v])
+            .count()
+    }
+}
+
+/// Check that `adj` is a valid input for Karger's algorithm: at least two
+/// nodes and every neighbor listed is itself a node of the map.
+fn validate_adjacency(adj: &HashMap<u32, Vec<u32>>) -> Result<()> {
+    if adj.len() < 2 {
+        return Err(Error::InvalidGraph(
+            "graf musi mieć co najmniej dwa wierzchołki",
+        ));
+    }
+    if adj.values().flatten().any(|v| !adj.contains_key(v)) {
+        return Err(Error::InvalidGraph(
+            "każdy sąsiad musi być wierzchołkiem grafu",
+        ));
+    }
+    Ok(())
+}
+
+/// Perform one run of Karger’s contraction algorithm on the given graph.
+/// The graph is represented as an adjacency map: each node → a Vec of its neighbors.
+/// Every undirected edge must be listed at both of its endpoints.
+///
+/// Returns the size of the cut found (i.e. the number of edges crossing when only two “super-nodes” remain),
+/// or [`Error::InvalidGraph`] if the graph has fewer than two nodes or refers to a missing node.
+pub fn karger_contraction<R: Rng + ?Sized>(
+    adj: &HashMap<u32, Vec<u32>>,
+    rng: &mut R,
+) -> Result<usize> {
+    validate_adjacency(adj)?;
+    // Make a working copy of the adjacency map so we can mutate it
+    let mut adj = adj.clone();
+
+    // While more than 2 super-nodes remain, keep contracting
+    while adj.len() > 2 {
+        // A super-node without edges is a cut of size 0 on its own
+        if adj.values().any(|nbrs| nbrs.is_empty()) {
+            return Ok(0);
+        }
+
+        // Pick a random edge (u, v):
+        // 1. Randomly pick a node u from the keys
+        // 2. Randomly pick a neighbor v of u
+        let (&u, nbrs) = adj.iter().choose(rng).unwrap();
+        let &v = nbrs.iter().choose(rng).unwrap();
+
+        // Merge v into u:
+        // 1) Append v’s adjacency list onto u’s adjacency list
+        let v_nbrs = adj.remove(&v).unwrap(); // Remove v and get its neighbors
+        let u_nbrs = adj.get_mut(&u).unwrap();
+        u_nbrs.extend(v_nbrs.iter().cloned());
+
+        // 2) For every neighbor w of v, replace occurrences of v with u in their adjacency lists
+        for w in v_nbrs {
+            if let Some(w_nbrs) = adj.get_mut(&w) {
+                for x in w_nbrs.iter_mut() {
+                    if *x == v {
+                        *x = u; // Replace v with u
+                    }
+                }
+            }
+        }
+
+        // 3) Remove self‐loops at u (edges from u to itself)
+        adj.get_mut(&u).unwrap().retain(|&x| x != u);
+    }
+
+    // Now exactly two nodes remain; pick one and return its number of edges (the cut size)
+    let (_, nbrs) = adj.iter().next().unwrap();
+    Ok(nbrs.len())
+}
+
+/// Run Karger’s algorithm `reps` times and return the smallest cut found.
+/// More repetitions increase the probability of finding the true min-cut.
+///
+/// Returns [`Error::InvalidParameter`] if `reps` is zero, and the errors of
+/// [`karger_contraction`] for an invalid graph.
+pub fn min_cut<R: Rng + ?Sized>(
+    adj: &HashMap<u32, Vec<u32>>,
+    reps: usize,
+    rng: &mut R,
+) -> Result<usize> {
+    if reps == 0 {
+        return Err(Error::InvalidParameter("reps musi być dodatnie"));
+    }
+    let mut best = usize::MAX;
+    for _ in 0..reps {
+        // Run the contraction algorithm multiple times and keep the smallest cut
+        best = best.min(karger_contraction(adj, rng)?);
+    }
+    Ok(best)
+}
@@ -0,0 +1,120 @@
+use crate::{Error, Result};
+
+/// Generuje wszystkie ciągi binarne długości `n` w porządku leksykograficznym.
+///
+/// Zwraca [`Error::InvalidParameter`], jeśli `n` nie pozwala wypisać
+/// wszystkich 2^n ciągów (n ≥ liczba bitów `usize`).
+pub fn generate_binary_sequences(n: usize) -> Result<Vec<String>> {
+    if n >= usize::BITS as usize {
+        return Err(Error::InvalidParameter(
+            "n musi być mniejsze niż liczba bitów usize",
+        ));
+    }
+    Ok((0..(1usize << n))
+        .map(|i| format!("{:0width$b}", i, width = n))
+        .collect())
+}
+
+/// Długość najdłuższego wspólnego podciągu `a` i `b`.
+///
+/// Porównanie odbywa się bajt po bajcie, więc dla tekstu spoza ASCII
+/// długość liczona jest w bajtach UTF-8.
+pub fn lcs(a: &str, b: &str) -> usize {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    let m = a.len();
+    let n = b.len();
+    let mut dp = vec![vec![0; n + 1]; m + 1];
+
+    for i in 1..=m {
+        for j in 1..=n {
+            if a[i - 1] == b[j - 1] {
+                dp[i][j] = dp[i - 1][j - 1] + 1;
+            } else {
+                dp[i][j] = dp[i - 1][j].max(dp[i][j - 1]);
+            }
+        }
+    }
+
+    dp[m][n]
+}
+
+/// Suma długości LCS po wszystkich uporządkowanych parach ciągów (łącznie
+/// z parami ciągu z samym sobą) oraz liczba tych par.
+pub fn total_lcs(sequences: &[String]) -> (usize, usize) {
+    let mut total_lcs = 0;
+    let mut count = 0;
+
+    for a in sequences {
+        for b in sequences {
+            total_lcs += lcs(a, b);
+            count += 1;
+        }
+    }
+    (total_lcs, count)
+}
+
+/// Średnia długość LCS po wszystkich uporządkowanych parach ciągów
+/// (łącznie z parami ciągu z samym sobą).
+///
+/// Zwraca [`Error::InvalidParameter`] dla pustego zbioru ciągów.
+pub fn average_lcs(sequences: &[String]) -> Result<f64> {
+    if sequences.is_empty() {
+        return Err(Error::InvalidParameter("zbiór ciągów nie może być pusty"));
+    }
+    let (total_lcs, count) = total_lcs(sequences);
+    Ok(total_lcs as f64 / count as f64)
+}
+
+/// Funkcja wyznaczająca najdłuższy wspólny podciąg dla trzech ciągów.
+/// Zwraca znaleziony LCS jako String.
+pub fn lcs3(s1: &str, s2: &str, s3: &str) -> String {
+    // Zamieniamy ciągi na wektory znaków.
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let c: Vec<char> = s3.chars().collect();
+
+    let n = a.len();
+    let m = b.len();
+    let k = c.len();
+
+    // Tworzymy trójwymiarową tablicę dp o wymiarach (n+1) x (m+1) x (k+1)
+    // dp[i][j][l] – długość LCS dla prefiksów a[0..i], b[0..j] oraz c[0..l].
+    let mut dp = vec![vec![vec![0; k + 1]; m + 1]; n + 1];
+
+    for i in 1..=n {
+        for j in 1..=m {
+            for l in 1..=k {
+                if a[i - 1] == b[j - 1] && a[i - 1] == c[l - 1] {
+                    dp[i][j][l] = dp[i - 1][j - 1][l - 1] + 1;
+                } else {
+                    dp[i][j][l] = dp[i - 1][j][l].max(dp[i][j - 1][l]).max(dp[i][j][l - 1]);
+                }
+            }
+        }
+    }
+
+    // Rekonstrukcja LCS poprzez backtracking
+    let mut i = n;
+    let mut j = m;
+    let mut l = k;
+    let mut lcs_chars = Vec::new();
+
+    while i > 0 && j > 0 && l > 0 {
+        if a[i - 1] == b[j - 1] && a[i - 1] == c[l - 1] {
+            lcs_chars.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+            l -= 1;
+        } else if dp[i - 1][j][l] >= dp[i][j - 1][l] && dp[i - 1][j][l] >= dp[i][j][l - 1] {
+            i -= 1;
+        } else if dp[i][j - 1][l] >= dp[i - 1][j][l] && dp[i][j - 1][l] >= dp[i][j][l - 1] {
+            j -= 1;
+        } else {
+            l -= 1;
+        }
+    }
+
+    lcs_chars.reverse();
+    lcs_chars.iter().collect()
+}
@@ -0,0 +1,23 @@
+//! Biblioteka algorytmów z kursu Algorithmics.
+//!
+//! Każdy z numerowanych programów w `src/bin` jest cienką nakładką na
+//! funkcje udostępnione w poniższych modułach:
+//!
+//! - [`strings`] – wyszukiwanie wzorców (automat prefiksowy, Rabin–Karp),
+//! - [`lcs`] – najdłuższy wspólny podciąg dwóch i trzech ciągów,
+//! - [`dna`] – sekwencje nukleotydów i wyszukiwanie genów,
+//! - [`poly`] – obliczanie wartości wielomianu schematem Hornera,
+//! - [`montecarlo`] – całkowanie metodą Monte Carlo i redukcja wariancji,
+//! - [`graph`] – max-cut (losowy i zderandomizowany) oraz min-cut Kargera,
+//! - [`derandomize`] – derandomizacja metodą warunkowych wartości oczekiwanych.
+
+pub mod derandomize;
+pub mod dna;
+pub mod error;
+pub mod graph;
+pub mod lcs;
+pub mod montecarlo;
+pub mod poly;
+pub mod strings;
+
+pub use error::{Error, Result};
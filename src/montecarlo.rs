@@ -0,0 +1,232 @@
+//! Monte Carlo integration and variance-reduction techniques.
+//!
+//! All estimators take the random number generator as a parameter, so callers
+//! can use `rand::rng()` or a seeded generator for reproducible runs.
+
+use rand::Rng;
+use std::f64::consts::PI;
+
+use crate::{Error, Result};
+
+/// The exact value of the integral ∫₀¹√(1-x²)dx, which is π/4
+pub const QUARTER_CIRCLE_AREA: f64 = PI / 4.0;
+
+/// Welford’s algorithm for running mean & (population) variance
+/// This struct keeps track of the running mean and variance in a numerically stable way.
+#[derive(Debug, Clone, Default)]
+pub struct RunningStats {
+    count: usize, // Number of samples seen so far
+    mean: f64,    // Running mean
+    m2: f64,      // Sum of squares of differences from the current mean
+}
+
+impl RunningStats {
+    /// Create a new RunningStats instance with zeroed fields
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+    /// Update the running statistics with a new sample x
+    pub fn update(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / (self.count as f64);
+        let delta2 = x - self.mean;
+        self.m2 += delta * delta2;
+    }
+    /// Number of samples seen so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Running mean (0 if no samples were seen)
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+    /// Return the population variance (divide by count, not count-1)
+    pub fn var(&self) -> f64 {
+        if self.count > 0 {
+            self.m2 / (self.count as f64)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl FromIterator<f64> for RunningStats {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut st = RunningStats::new();
+        for x in iter {
+            st.update(x);
+        }
+        st
+    }
+}
+
+/// Monte Carlo estimator for ∫₀^π sin(x) dx
+///
+/// We draw X₁,…,X_N ~ Uniform(0,π) and compute
+///   Ĩ_N = π * (1/N) * Σ sin(X_i).
+/// This estimator is unbiased:
+///   E[Ĩ_N] = ∫₀^π sin(x) dx = 2.
+/// Its variance is Var(Ĩ_N) = π² Var(sin(X))/N,
+/// with Var(sin(X)) = ½ − 4/π².
+/// By the CLT, Ĩ_N ≈ N(2, π²σ²/N) for large N,
+/// so you can form approximate confidence intervals:
+///   Ĩ_N ± z_{α/2}·π·σ/√N.
+///
+/// Returns [`Error::InvalidParameter`] if `n_samples` is zero.
+pub fn sin_integral<R: Rng + ?Sized>(n_samples: u64, rng: &mut R) -> Result<f64> {
+    if n_samples == 0 {
+        return Err(Error::InvalidParameter("n_samples musi być dodatnie"));
+    }
+    let mut sum = 0.0; // Accumulate the sum of sin(x) values
+
+    // Draw n_samples random points in [0, π) and sum sin(x) at each point
+    for _ in 0..n_samples {
+        let x = rng.random_range(0.0..PI); // Uniform random x in [0, π)
+        sum += x.sin(); // Add sin(x) to the running total
+    }
+
+    // The average value of sin(x) times the interval length (π) gives the integral estimate
+    Ok((sum / n_samples as f64) * PI)
+}
+
+/// Monte Carlo: 1D basic sampling
+/// Estimate ∫₀¹√(1-x²)dx by averaging sqrt(1 - x^2) for x ~ Uniform[0,1]
+///
+/// Returns [`Error::InvalidParameter`] if `n` is zero.
+pub fn mc_1d_basic<R: Rng + ?Sized>(n: u64, rng: &mut R) -> Result<f64> {
+    if n == 0 {
+        return Err(Error::InvalidParameter("n musi być dodatnie"));
+    }
+    let mut sum = 0.0;
+    for _ in 0..n {
+        let x: f64 = rng.random(); // Uniform random in [0,1)
+        sum += (1.0 - x * x).sqrt();
+    }
+    Ok(sum / (n as f64)) // Return the average
+}
+
+/// Monte Carlo: 1D stratified sampling
+/// Divide [0,1] into `strata` intervals, sample uniformly within each, and average
+///
+/// Uses `n / strata` samples per stratum; returns [`Error::InvalidParameter`]
+/// if `strata` is zero or larger than `n`.
+pub fn mc_1d_stratified<R: Rng + ?Sized>(n: u64, strata: usize, rng: &mut R) -> Result<f64> {
+    if strata == 0 || (n as usize) < strata {
+        return Err(Error::InvalidParameter("strata musi należeć do 1..=n"));
+    }
+    let m = (n as usize) / strata; // Samples per stratum
+    let width = 1.0 / (strata as f64); // Width of each stratum
+    let mut total = 0.0;
+    for k in 0..strata {
+        let base = (k as f64) * width; // Start of the k-th stratum
+        let mut sum_k = 0.0;
+        for _ in 0..m {
+            let u: f64 = rng.random_range(0.0..1.0); // Uniform in [0,1)
+            let x = base + u * width; // Map to [base, base+width)
+            sum_k += (1.0 - x * x).sqrt();
+        }
+        total += sum_k / (m as f64); // Average for this stratum
+    }
+    Ok(total / (strata as f64)) // Average over all strata
+}
+
+/// Monte Carlo: 1D antithetic variates
+/// For each sample x, also use 1-x to reduce variance
+///
+/// Returns [`Error::InvalidParameter`] unless `n` is positive and even.
+pub fn mc_1d_antithetic<R: Rng + ?Sized>(n: u64, rng: &mut R) -> Result<f64> {
+    if n == 0 || !n.is_multiple_of(2) {
+        return Err(Error::InvalidParameter("n musi być dodatnie i parzyste"));
+    }
+    let half = n / 2; // Number of pairs
+    let mut sum = 0.0;
+    for _ in 0..half {
+        let u: f64 = rng.random();
+        sum += (1.0 - u * u).sqrt();
+        let v = 1.0 - u; // Antithetic pair
+        sum += (1.0 - v * v).sqrt();
+    }
+    Ok(sum / (n as f64))
+}
+
+/// Monte Carlo: 2D basic (crude accept-reject)
+/// Estimate the area under the curve by counting points below sqrt(1-x^2)
+///
+/// Returns [`Error::InvalidParameter`] if `n` is zero.
+pub fn mc_2d_basic<R: Rng + ?Sized>(n: u64, rng: &mut R) -> Result<f64> {
+    if n == 0 {
+        return Err(Error::InvalidParameter("n musi być dodatnie"));
+    }
+    let mut count = 0u64;
+    for _ in 0..n {
+        let x: f64 = rng.random();
+        let y: f64 = rng.random();
+        if y <= (1.0 - x * x).sqrt() {
+            count += 1;
+        }
+    }
+    Ok((count as f64) / (n as f64))
+}
+
+/// Monte Carlo: 2D stratified sampling
+/// Divide [0,1]x[0,1] into strata x strata grid, sample uniformly in each cell
+///
+/// Uses `n / strata²` samples per cell; returns [`Error::InvalidParameter`]
+/// if `strata` is zero or `strata²` is larger than `n`.
+pub fn mc_2d_stratified<R: Rng + ?Sized>(n: u64, strata: usize, rng: &mut R) -> Result<f64> {
+    let cells = strata.saturating_mul(strata); // Total number of grid cells
+    if strata == 0 || (n as usize) < cells {
+        return Err(Error::InvalidParameter("strata² musi należeć do 1..=n"));
+    }
+    let m = (n as usize) / cells; // Samples per cell
+    let width = 1.0 / (strata as f64); // Width/height of each cell
+    let mut total = 0.0;
+    for i in 0..strata {
+        for j in 0..strata {
+            let x0 = (i as f64) * width; // Cell's x origin
+            let y0 = (j as f64) * width; // Cell's y origin
+            let mut count_cell = 0usize;
+            for _ in 0..m {
+                let ux: f64 = rng.random();
+                let uy: f64 = rng.random();
+                let x = x0 + ux * width; // Map to cell in x
+                let y = y0 + uy * width; // Map to cell in y
+                if y <= (1.0 - x * x).sqrt() {
+                    count_cell += 1;
+                }
+            }
+            total += (count_cell as f64) / (m as f64); // Fraction in this cell
+        }
+    }
+    Ok(total / (cells as f64)) // Average over all cells
+}
+
+/// Monte Carlo: 2D antithetic variates
+/// For each (x, y), also use (1-x, 1-y) to reduce variance
+///
+/// Returns [`Error::InvalidParameter`] unless `n` is positive and even.
+pub fn mc_2d_antithetic<R: Rng + ?Sized>(n: u64, rng: &mut R) -> Result<f64> {
+    if n == 0 || !n.is_multiple_of(2) {
+        return Err(Error::InvalidParameter("n musi być dodatnie i parzyste"));
+    }
+    let half = n / 2;
+    let mut count = 0u64;
+    for _ in 0..half {
+        let x: f64 = rng.random();
+        let y: f64 = rng.random();
+        if y <= (1.0 - x * x).sqrt() {
+            count += 1;
+        }
+        let x2 = 1.0 - x;
+        let y2 = 1.0 - y;
+        if y2 <= (1.0 - x2 * x2).sqrt() {
+            count += 1;
+        }
+    }
+    Ok((count as f64) / (n as f64))
+}
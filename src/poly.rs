@@ -0,0 +1,12 @@
+/// Oblicza wartość wielomianu w punkcie `x` schematem Hornera.
+///
+/// Współczynniki podawane są od wyrazu wolnego: `coeffs[i]` stoi przy `x^i`.
+/// Dla pustej listy współczynników zwraca 0.
+pub fn horner(coeffs: &[f64], x: f64) -> f64 {
+    let mut result = 0.0;
+    // Iterujemy po współczynnikach w kolejności od najwyższej potęgi do wyrazu wolnego.
+    for &a in coeffs.iter().rev() {
+        result = a + x * result;
+    }
+    result
+}
@@ -0,0 +1,336 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use super::automaton::decode_char;
+use super::dot::{DotOptions, write_dot};
+
+/// Sposób wybierania wystąpień, gdy wzorce nakładają się na siebie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchKind {
+    /// Wszystkie wystąpienia wszystkich wzorców, łącznie z nakładającymi się.
+    #[default]
+    Standard,
+    /// Rozłączne wystąpienia szukane od lewej; spośród wzorców zaczynających
+    /// się na tej samej pozycji wygrywa ten, który podano wcześniej.
+    LeftmostFirst,
+    /// Rozłączne wystąpienia szukane od lewej; spośród wzorców zaczynających
+    /// się na tej samej pozycji wygrywa najdłuższy.
+    LeftmostLongest,
+}
+
+/// Wystąpienie wzorca o numerze `pattern` w tekście na pozycjach
+/// (w bajtach) `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Automat Aho–Corasick dla wielu wzorców jednocześnie.
+///
+/// Automat to drzewo trie wzorców z przejściami `znak -> stan` (jak w
+/// [`build_prefix_automaton`](super::build_prefix_automaton)) uzupełnione o
+/// krawędzie porażki: `fail[q]` to stan odpowiadający najdłuższemu właściwemu
+/// sufiksowi słowa stanu `q`, który jest prefiksem któregoś wzorca.
+/// Po [`compile_dfa`](Self::compile_dfa) krawędzie porażki są wbudowane w
+/// pełną tablicę przejść, więc każdy znak tekstu to jedno przejście.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    goto: Vec<HashMap<char, usize>>, // przejścia trie, a po kompilacji – pełny DFA
+    fail: Vec<usize>,
+    outputs: Vec<Vec<usize>>, // numery wzorców kończących się w danym stanie
+    pattern_lens: Vec<usize>, // długości wzorców w bajtach
+    max_len: usize,
+    kind: MatchKind,
+    is_dfa: bool,
+}
+
+impl AhoCorasick {
+    /// Buduje automat (trie + krawędzie porażki) dla podanych wzorców.
+    /// Numer wzorca w [`Match`] to jego indeks w `patterns`. Pusty wzorzec
+    /// kończy się w korzeniu, więc występuje na każdej granicy znaku.
+    pub fn new<P: AsRef<str>>(patterns: &[P], kind: MatchKind) -> Self {
+        let mut goto: Vec<HashMap<char, usize>> = vec![HashMap::new()];
+        let mut outputs: Vec<Vec<usize>> = vec![Vec::new()];
+        let mut pattern_lens = Vec::with_capacity(patterns.len());
+
+        // Budujemy drzewo trie wzorców.
+        for (id, p) in patterns.iter().enumerate() {
+            let p = p.as_ref();
+            let mut state = 0;
+            for c in p.chars() {
+                state = match goto[state].get(&c) {
+                    Some(&next) => next,
+                    None => {
+                        goto.push(HashMap::new());
+                        outputs.push(Vec::new());
+                        let next = goto.len() - 1;
+                        goto[state].insert(c, next);
+                        next
+                    }
+                };
+            }
+            outputs[state].push(id);
+            pattern_lens.push(p.len());
+        }
+
+        // Krawędzie porażki wyznaczamy przechodząc trie wszerz: stany z
+        // mniejszej głębokości mają już gotowe fail.
+        let mut fail = vec![0; goto.len()];
+        let mut queue: VecDeque<usize> = goto[0].values().cloned().collect();
+        // Stany głębokości 1 mają fail = 0, więc dziedziczą puste wzorce.
+        let empty = outputs[0].clone();
+        for &v in &queue {
+            outputs[v].extend(&empty);
+        }
+        while let Some(u) = queue.pop_front() {
+            let edges: Vec<(char, usize)> = goto[u].iter().map(|(&c, &v)| (c, v)).collect();
+            for (c, v) in edges {
+                let mut f = fail[u];
+                while f != 0 && !goto[f].contains_key(&c) {
+                    f = fail[f];
+                }
+                fail[v] = goto[f].get(&c).copied().filter(|&w| w != v).unwrap_or(0);
+                // Wzorce kończące się w stanie fail[v] kończą się też w v.
+                let inherited = outputs[fail[v]].clone();
+                outputs[v].extend(inherited);
+                queue.push_back(v);
+            }
+        }
+
+        let max_len = pattern_lens.iter().copied().max().unwrap_or(0);
+        AhoCorasick {
+            goto,
+            fail,
+            outputs,
+            pattern_lens,
+            max_len,
+            kind,
+            is_dfa: false,
+        }
+    }
+
+    /// Zamienia automat w pełny DFA w tym samym kształcie co
+    /// [`build_prefix_automaton`](super::build_prefix_automaton):
+    ///   dfa[0][c] = goto[0][c] lub 0,
+    ///   dfa[q][c] = goto[q][c] lub dfa[fail[q]][c] (stany w kolejności BFS).
+    pub fn compile_dfa(mut self) -> Self {
+        if self.is_dfa {
+            return self;
+        }
+        // Alfabet – wszystkie znaki występujące we wzorcach.
+        let alphabet: HashSet<char> = self.goto.iter().flat_map(|t| t.keys().cloned()).collect();
+
+        let mut order = Vec::with_capacity(self.goto.len());
+        let mut queue: VecDeque<usize> = VecDeque::from([0]);
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+            queue.extend(self.goto[u].values().cloned());
+        }
+
+        for &c in &alphabet {
+            self.goto[0].entry(c).or_insert(0);
+        }
+        for &q in order.iter().skip(1) {
+            let f = self.fail[q];
+            for &c in &alphabet {
+                if !self.goto[q].contains_key(&c) {
+                    let next_state = self.goto[f][&c];
+                    self.goto[q].insert(c, next_state);
+                }
+            }
+        }
+        self.is_dfa = true;
+        self
+    }
+
+    /// Tablica przejść: przejścia trie albo, po [`compile_dfa`](Self::compile_dfa),
+    /// pełny DFA.
+    pub fn states(&self) -> &[HashMap<char, usize>] {
+        &self.goto
+    }
+
+    /// Krawędzie porażki: `failure_links()[q]` to stan najdłuższego właściwego
+    /// sufiksu słowa stanu `q` będącego prefiksem wzorca.
+    pub fn failure_links(&self) -> &[usize] {
+        &self.fail
+    }
+
+    /// Trie (albo DFA po [`compile_dfa`](Self::compile_dfa)) w formacie DOT.
+    /// Stany, w których kończy się jakiś wzorzec, są akceptujące; dla trie
+    /// krawędzie porażki rysowane są linią przerywaną.
+    pub fn to_dot(&self, options: DotOptions) -> String {
+        let accepting: Vec<usize> = (0..self.goto.len())
+            .filter(|&q| !self.outputs[q].is_empty())
+            .collect();
+        let failure: Vec<(usize, usize)> = if self.is_dfa {
+            Vec::new()
+        } else {
+            self.fail
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(q, &f)| (q, f))
+                .collect()
+        };
+        write_dot(&self.goto, &accepting, &failure, options)
+    }
+
+    /// Liczba wzorców.
+    pub fn pattern_count(&self) -> usize {
+        self.pattern_lens.len()
+    }
+
+    /// Sposób wybierania wystąpień podany przy budowie.
+    pub fn match_kind(&self) -> MatchKind {
+        self.kind
+    }
+
+    /// Czy automat został zamieniony w pełny DFA.
+    pub fn is_dfa(&self) -> bool {
+        self.is_dfa
+    }
+
+    /// Przejście ze stanu `state` po znaku `c` (`None` – znak spoza UTF-8).
+    fn next_state(&self, mut state: usize, c: Option<char>) -> usize {
+        let Some(c) = c else {
+            return 0;
+        };
+        if self.is_dfa {
+            return *self.goto[state].get(&c).unwrap_or(&0);
+        }
+        loop {
+            if let Some(&next) = self.goto[state].get(&c) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.fail[state];
+        }
+    }
+
+    /// Iterator po wystąpieniach wzorców w `text` zgodnie z [`MatchKind`].
+    /// Wystąpienia zwracane są w kolejności pozycji końca (dla `Standard`)
+    /// albo początku (dla wariantów `Leftmost*`).
+    pub fn find_iter<'a, 't>(&'a self, text: &'t str) -> FindIter<'a, 't> {
+        FindIter::new(self, text.as_bytes())
+    }
+
+    /// Jak [`find_iter`](Self::find_iter), ale dla dowolnych bajtów;
+    /// niepoprawne bajty UTF-8 nie pasują do żadnego wzorca.
+    pub fn find_bytes_iter<'a, 't>(&'a self, haystack: &'t [u8]) -> FindIter<'a, 't> {
+        FindIter::new(self, haystack)
+    }
+}
+
+/// Iterator po wystąpieniach zwracany przez [`AhoCorasick::find_iter`].
+#[derive(Debug, Clone)]
+pub struct FindIter<'a, 't> {
+    ac: &'a AhoCorasick,
+    haystack: &'t [u8],
+    pos: usize,
+    state: usize,
+    output: usize, // indeks kolejnego wzorca do zgłoszenia z outputs[state]
+    // Tylko dla wariantów Leftmost*: najlepsza znana kandydatura dla każdego
+    // początku (uporządkowane po początku) i najmniejszy dopuszczalny początek
+    // kolejnego wystąpienia.
+    candidates: BTreeMap<usize, Match>,
+    cursor: usize,
+}
+
+impl<'a, 't> FindIter<'a, 't> {
+    fn new(ac: &'a AhoCorasick, haystack: &'t [u8]) -> Self {
+        FindIter {
+            ac,
+            haystack,
+            pos: 0,
+            state: 0,
+            output: 0,
+            candidates: BTreeMap::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Kolejne wystąpienie w trybie `Standard` (wszystkie, w kolejności końców).
+    fn next_overlapping(&mut self) -> Option<Match> {
+        loop {
+            if let Some(&pattern) = self.ac.outputs[self.state].get(self.output) {
+                self.output += 1;
+                return Some(Match {
+                    pattern,
+                    start: self.pos - self.ac.pattern_lens[pattern],
+                    end: self.pos,
+                });
+            }
+            if self.pos >= self.haystack.len() {
+                return None;
+            }
+            let (c, len) = decode_char(&self.haystack[self.pos..]);
+            self.state = self.ac.next_state(self.state, c);
+            self.pos += len;
+            self.output = 0;
+        }
+    }
+
+    /// Zapamiętuje `m`, jeśli wygrywa z dotychczasową kandydaturą o tym samym
+    /// początku.
+    fn add_candidate(&mut self, m: Match) {
+        let kind = self.ac.kind;
+        self.candidates
+            .entry(m.start)
+            .and_modify(|best| {
+                let wins = match kind {
+                    MatchKind::LeftmostLongest => {
+                        (m.end, std::cmp::Reverse(m.pattern))
+                            > (best.end, std::cmp::Reverse(best.pattern))
+                    }
+                    _ => m.pattern < best.pattern,
+                };
+                if wins {
+                    *best = m;
+                }
+            })
+            .or_insert(m);
+    }
+
+    /// Kolejne wystąpienie w trybach `Leftmost*`. Wszystkie wystąpienia
+    /// zaczynające się na pozycji `s` kończą się najpóźniej na `s + max_len`,
+    /// więc po przejściu tej pozycji można wybrać zwycięzcę spośród kandydatów.
+    fn next_leftmost(&mut self) -> Option<Match> {
+        loop {
+            let exhausted =
+                self.pos >= self.haystack.len() && self.output >= self.ac.outputs[self.state].len();
+            if let Some((&start, &best)) = self.candidates.first_key_value()
+                && (exhausted || self.pos >= start + self.ac.max_len)
+            {
+                // Po pustym wystąpieniu kolejne musi zacząć się dalej, inaczej
+                // to samo wystąpienie byłoby zgłaszane w nieskończoność.
+                self.cursor = if best.start == best.end {
+                    best.end + 1
+                } else {
+                    best.end
+                };
+                self.candidates = self.candidates.split_off(&self.cursor);
+                return Some(best);
+            }
+            match self.next_overlapping() {
+                Some(m) if m.start >= self.cursor => self.add_candidate(m),
+                Some(_) => {}
+                None if self.candidates.is_empty() => return None,
+                None => {}
+            }
+        }
+    }
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        match self.ac.kind {
+            MatchKind::Standard => self.next_overlapping(),
+            MatchKind::LeftmostFirst | MatchKind::LeftmostLongest => self.next_leftmost(),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+/// Przybliżone wystąpienie wzorca: `end` to pozycja tuż za ostatnim symbolem
+/// dopasowanego fragmentu tekstu, a `distance` – odległość od wzorca
+/// (liczba niezgodności albo edycji).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApproxMatch {
+    pub end: usize,
+    pub distance: usize,
+}
+
+/// Wystąpienia `pattern` w `text` z co najwyżej `k` niezgodnościami
+/// (odległość Hamminga), w kolejności pozycji. Fragment `text[end - m..end]`
+/// porównywany jest symbol po symbolu do przekroczenia `k` niezgodności,
+/// więc czas to O(n · min(m, k + 1)) dla typowych tekstów i O(nm) w najgorszym
+/// przypadku.
+///
+/// Pusty wzorzec występuje z odległością 0 na każdej pozycji 0..=text.len().
+pub fn hamming_search<T: PartialEq>(text: &[T], pattern: &[T], k: usize) -> Vec<ApproxMatch> {
+    let (n, m) = (text.len(), pattern.len());
+    if m > n {
+        return Vec::new();
+    }
+    let mut wynik = Vec::new();
+    for i in 0..=n - m {
+        let mut distance = 0;
+        for (a, b) in text[i..i + m].iter().zip(pattern) {
+            if a != b {
+                distance += 1;
+                if distance > k {
+                    break;
+                }
+            }
+        }
+        if distance <= k {
+            wynik.push(ApproxMatch {
+                end: i + m,
+                distance,
+            });
+        }
+    }
+    wynik
+}
+
+/// Końce wszystkich fragmentów `text`, od których `pattern` różni się o co
+/// najwyżej `k` edycji (wstawienie, usunięcie, zamiana symbolu), wraz z
+/// najmniejszą taką odległością, w kolejności pozycji.
+///
+/// Programowanie dynamiczne Sellersa kolumna po kolumnie z odcięciem
+/// Ukkonena: `c[i]` to odległość `pattern[..i]` od najlepszego fragmentu
+/// kończącego się na bieżącej pozycji, a wiersze poniżej ostatniego
+/// aktywnego (`c[i] ≤ k`) mają wartość większą niż `k` i nie są liczone.
+/// Oczekiwany czas to O(kn) zamiast O(mn).
+///
+/// Pusty fragment tekstu (np. `end = 0`) też jest kandydatem, więc dla
+/// `m ≤ k` każda pozycja jest wystąpieniem.
+pub fn edit_search<T: PartialEq>(text: &[T], pattern: &[T], k: usize) -> Vec<ApproxMatch> {
+    let m = pattern.len();
+    let mut c: Vec<usize> = (0..=m).collect();
+    // Ostatni wiersz z wartością ≤ k (c[0] = 0, więc zawsze istnieje).
+    let mut last_active = k.min(m);
+    let mut wynik = Vec::new();
+    if m <= k {
+        wynik.push(ApproxMatch {
+            end: 0,
+            distance: m,
+        });
+    }
+
+    for (j, t) in text.iter().enumerate() {
+        let rows = (last_active + 1).min(m);
+        let mut diag = 0; // c[i - 1] z poprzedniej kolumny
+        for i in 1..=rows {
+            // Poniżej ostatniego aktywnego wiersza wartości są większe niż k,
+            // więc wystarczy przyjąć k + 1.
+            let old = if i <= last_active { c[i] } else { k + 1 };
+            let new = if pattern[i - 1] == *t {
+                diag
+            } else {
+                1 + diag.min(old).min(c[i - 1])
+            };
+            diag = old;
+            c[i] = new;
+        }
+        last_active = rows;
+        while c[last_active] > k {
+            last_active -= 1;
+        }
+        if last_active == m {
+            wynik.push(ApproxMatch {
+                end: j + 1,
+                distance: c[m],
+            });
+        }
+    }
+    wynik
+}
+
+/// Przelicza końce wystąpień w ciągu znaków na pozycje w bajtach.
+fn to_byte_offsets(text: &str, matches: Vec<ApproxMatch>) -> Vec<ApproxMatch> {
+    let offsets: Vec<usize> = text
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    matches
+        .into_iter()
+        .map(|hit| ApproxMatch {
+            end: offsets[hit.end],
+            ..hit
+        })
+        .collect()
+}
+
+/// [`hamming_search`] na znakach Unicode; `end` to pozycja w bajtach.
+pub fn hamming_search_str(text: &str, pattern: &str, k: usize) -> Vec<ApproxMatch> {
+    let (t, p): (Vec<char>, Vec<char>) = (text.chars().collect(), pattern.chars().collect());
+    to_byte_offsets(text, hamming_search(&t, &p, k))
+}
+
+/// [`edit_search`] na znakach Unicode; `end` to pozycja w bajtach.
+pub fn edit_search_str(text: &str, pattern: &str, k: usize) -> Vec<ApproxMatch> {
+    let (t, p): (Vec<char>, Vec<char>) = (text.chars().collect(), pattern.chars().collect());
+    to_byte_offsets(text, edit_search(&t, &p, k))
+}
@@ -0,0 +1,112 @@
+use super::periodicity::overlap;
+
+/// Wynik składania fragmentów w nadsłowo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Superstring {
+    /// Nadsłowo zawierające wszystkie fragmenty.
+    pub superstring: String,
+    /// Indeksy fragmentów w kolejności, w jakiej zostały sklejone. Pomija
+    /// fragmenty zawarte w innych (oraz powtórzenia), bo nie wnoszą nic do wyniku.
+    pub order: Vec<usize>,
+    /// `positions[i]` – pozycja (w bajtach) `i`-tego fragmentu w nadsłowie:
+    /// dla fragmentów z `order` miejsce, w którym zostały wklejone (nie zawsze
+    /// pierwsze wystąpienie), dla pominiętych – ich pierwsze wystąpienie.
+    pub positions: Vec<usize>,
+}
+
+/// Macierz zakładek: `m[i][j]` to długość najdłuższego sufiksu `fragments[i]`,
+/// który jest prefiksem `fragments[j]`, czyli [`overlap`]`(fragments[j], fragments[i])`.
+/// Na przekątnej są zera. Każda para liczona jest w czasie liniowym.
+pub fn overlap_matrix<T: PartialEq, S: AsRef<[T]>>(fragments: &[S]) -> Vec<Vec<usize>> {
+    let k = fragments.len();
+    let mut m = vec![vec![0; k]; k];
+    for i in 0..k {
+        for j in 0..k {
+            if i != j {
+                m[i][j] = overlap(fragments[j].as_ref(), fragments[i].as_ref());
+            }
+        }
+    }
+    m
+}
+
+/// Zachłanne najkrótsze wspólne nadsłowo (ang. *shortest common superstring*).
+///
+/// Najpierw odrzuca fragmenty zawarte w innych (z powtórzeń zostaje pierwszy),
+/// potem przegląda pary w kolejności malejących zakładek z [`overlap_matrix`]
+/// i skleja `i` z `j`, jeśli `i` nie ma jeszcze następnika, `j` poprzednika,
+/// a połączenie nie zamyka cyklu. Powstałe łańcuchy są łączone bez zakładek.
+/// To klasyczna heurystyka – wynik jest co najwyżej kilkukrotnie dłuższy od
+/// optymalnego, ale nie zawsze najkrótszy.
+///
+/// Zakładki liczone są na bajtach; ponieważ są jednocześnie sufiksem i
+/// prefiksem poprawnego UTF-8, zawsze zaczynają się i kończą na granicy znaku.
+pub fn shortest_common_superstring<S: AsRef<str>>(fragments: &[S]) -> Superstring {
+    let frags: Vec<&str> = fragments.iter().map(AsRef::as_ref).collect();
+    let k = frags.len();
+
+    let contained = |i: usize| {
+        (0..k).any(|j| {
+            j != i && frags[j].contains(frags[i]) && (frags[j].len() > frags[i].len() || j < i)
+        })
+    };
+    let kept: Vec<usize> = (0..k).filter(|&i| !contained(i)).collect();
+    let kept_frags: Vec<&[u8]> = kept.iter().map(|&i| frags[i].as_bytes()).collect();
+    let m = overlap_matrix(&kept_frags);
+
+    // Krawędzie (zakładka, i, j) od największej zakładki; remisy po indeksach.
+    let c = kept.len();
+    let mut edges: Vec<(usize, usize, usize)> = (0..c)
+        .flat_map(|i| (0..c).filter(move |&j| j != i).map(move |j| (i, j)))
+        .map(|(i, j)| (m[i][j], i, j))
+        .collect();
+    edges.sort_by(|a, b| b.0.cmp(&a.0).then((a.1, a.2).cmp(&(b.1, b.2))));
+
+    let mut next = vec![None; c];
+    let mut has_prev = vec![false; c];
+    // Koniec łańcucha zaczynającego się w danym wierzchołku i odwrotnie –
+    // wystarcza do wykrywania cykli bez pełnego union-find.
+    let mut tail: Vec<usize> = (0..c).collect();
+    let mut head: Vec<usize> = (0..c).collect();
+    for (_, i, j) in edges {
+        if next[i].is_some() || has_prev[j] || head[i] == j {
+            continue;
+        }
+        next[i] = Some(j);
+        has_prev[j] = true;
+        let (h, t) = (head[i], tail[j]);
+        tail[h] = t;
+        head[t] = h;
+    }
+
+    let mut superstring = String::new();
+    let mut order = Vec::with_capacity(c);
+    let mut positions = vec![0; k];
+    for start in (0..c).filter(|&i| !has_prev[i]) {
+        let mut cur = start;
+        let mut shared = 0; // zakładka z poprzednim fragmentem łańcucha
+        loop {
+            positions[kept[cur]] = superstring.len() - shared;
+            superstring.push_str(&frags[kept[cur]][shared..]);
+            order.push(kept[cur]);
+            match next[cur] {
+                Some(j) => {
+                    shared = m[cur][j];
+                    cur = j;
+                }
+                None => break,
+            }
+        }
+    }
+    for i in 0..k {
+        if !kept.contains(&i) {
+            positions[i] = superstring.find(frags[i]).unwrap_or(0);
+        }
+    }
+
+    Superstring {
+        superstring,
+        order,
+        positions,
+    }
+}
@@ -0,0 +1,431 @@
+use std::collections::{HashMap, HashSet};
+
+use super::dot::{DotOptions, to_dot, transition_table};
+use super::matcher::Matcher;
+use super::minimize::Automaton;
+
+/// Buduje prefiksowy automat skończony dla wzorca `p`.
+///
+/// Automat reprezentowany jest jako wektor map, gdzie:
+/// - stany: 0 ..= m (m = liczba znaków wzorca), stan m oznacza pełny wzorzec,
+/// - każda mapa dla stanu q zawiera przejścia: znak -> kolejny stan.
+///
+/// Algorytm wzorowany jest na klasycznym podejściu:
+///   dfa[0][c] = 0 dla każdego znaku c,
+///   dfa[0][P[0]] = 1,
+///   dla j = 1..m-1:
+///       dla każdego c ∈ alfabet: dfa[j][c] = dfa[longest_prefix_sufix][c]
+///       dfa[j][P[j]] = j+1
+///       longest_prefix_sufix = dfa[longest_prefix_sufix][P[j]]
+///
+/// Dla pustego wzorca automat ma jeden stan 0 = m bez przejść.
+pub fn build_prefix_automaton(p: &str) -> Vec<HashMap<char, usize>> {
+    let pattern: Vec<char> = p.chars().collect();
+    let m = pattern.len();
+    if m == 0 {
+        return vec![HashMap::new()];
+    }
+
+    // Wyznaczamy alfabet – zbiór znaków występujących we wzorcu.
+    let alphabet: HashSet<char> = pattern.iter().cloned().collect();
+
+    // Inicjujemy automat: m+1 stanów (od 0 do m)
+    let mut dfa: Vec<HashMap<char, usize>> = vec![HashMap::new(); m + 1];
+
+    // Dla stanu 0, ustawiamy przejścia dla wszystkich znaków z alfabetu na 0.
+    for &c in &alphabet {
+        dfa[0].insert(c, 0);
+    }
+    // Ustawiamy specyficzne przejście dla pierwszego znaku wzorca.
+    dfa[0].insert(pattern[0], 1);
+
+    let mut longest_prefix_sufix = 0; // zmienna pomocnicza określająca najdłuższy prefiks będący sufiksem.
+    for j in 1..m {
+        // Dla każdego znaku z alfabetu kopiujemy przejścia z automatu w stanie longest_prefix_sufix.
+        for &c in &alphabet {
+            let next_state: usize = *dfa[longest_prefix_sufix].get(&c).unwrap_or(&0);
+            dfa[j].insert(c, next_state);
+        }
+        // Ustawiamy przejście dla znaku wzorca na aktualnym stanie.
+        dfa[j].insert(pattern[j], j + 1);
+        // Aktualizujemy longest_prefix_sufix – najdłuższy prefiks będący sufiksem dotychczasowego wzorca.
+        longest_prefix_sufix = *dfa[longest_prefix_sufix].get(&pattern[j]).unwrap_or(&0);
+    }
+    // Dla stanu m (pełny wzorzec) również uzupełniamy przejścia.
+    for &c in &alphabet {
+        let next_state = *dfa[longest_prefix_sufix].get(&c).unwrap_or(&0);
+        dfa[m].insert(c, next_state);
+    }
+
+    dfa
+}
+
+/// Przeszukuje tekst przy użyciu zbudowanego automatu.
+/// Funkcja iteruje po kolejnych znakach tekstu, dokonując przejścia między stanami.
+/// Jeśli osiągniemy stan równy długości wzorca `m`, oznacza to, że wzorzec został znaleziony.
+pub fn pattern_exists(text: &str, automaton: &[HashMap<char, usize>], m: usize) -> bool {
+    // Pusty wzorzec występuje w każdym tekście.
+    if m == 0 {
+        return true;
+    }
+    let mut state = 0;
+    for c in text.chars() {
+        // Jeśli nie mamy zdefiniowanego przejścia dla danego znaku, domyślnie przechodzimy do stanu 0.
+        state = *automaton[state].get(&c).unwrap_or(&0);
+        if state == m {
+            return true;
+        }
+    }
+    false
+}
+
+/// Łączy budowę automatu oraz przeszukiwanie tekstu.
+/// Zwraca `true`, jeśli wzorzec `pattern` został znaleziony w `text`, w przeciwnym razie `false`.
+pub fn find_pattern(text: &str, pattern: &str) -> bool {
+    let automaton = build_prefix_automaton(pattern);
+    let m = automaton.len() - 1;
+    pattern_exists(text, &automaton, m)
+}
+
+/// Sposób przechowywania przejść automatu, wybierany przy budowie
+/// [`PrefixAutomaton`]. Wszystkie reprezentacje dają te same dopasowania.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Representation {
+    /// Wektor map `znak -> stan`, jak w [`build_prefix_automaton`].
+    #[default]
+    HashMap,
+    /// Płaska tablica `[stan * 256 + bajt]` – automat działa na bajtach UTF-8,
+    /// a każdy bajt tekstu kosztuje jeden odczyt z tablicy.
+    ByteTable,
+    /// Tablica `[stan * k + klasa]`, gdzie znaki wzorca mają klasy 1..k,
+    /// a wszystkie pozostałe znaki wspólną klasę 0.
+    ClassTable,
+}
+
+/// Alfabet skompresowany do klas równoważności: znaki spoza wzorca
+/// zachowują się identycznie (prowadzą do stanu 0), więc dzielą klasę 0.
+#[derive(Debug, Clone)]
+struct ClassTable {
+    ascii: [usize; 128],       // klasy znaków ASCII, indeksowane kodem znaku
+    other: Vec<(char, usize)>, // klasy pozostałych znaków, posortowane po znaku
+    classes: usize,            // liczba klas (łącznie z klasą 0)
+    table: Vec<usize>,         // table[q * classes + klasa] = kolejny stan
+}
+
+impl ClassTable {
+    fn new(dfa: &[HashMap<char, usize>]) -> Self {
+        // Alfabet wzorca to klucze mapy stanu 0.
+        let mut alphabet: Vec<char> = dfa[0].keys().cloned().collect();
+        alphabet.sort_unstable();
+        let classes = alphabet.len() + 1;
+
+        let mut ascii = [0; 128];
+        let mut other = Vec::new();
+        let mut table = vec![0; dfa.len() * classes];
+        for (k, &c) in alphabet.iter().enumerate() {
+            let class = k + 1;
+            if c.is_ascii() {
+                ascii[c as usize] = class;
+            } else {
+                other.push((c, class));
+            }
+            for (q, transitions) in dfa.iter().enumerate() {
+                table[q * classes + class] = *transitions.get(&c).unwrap_or(&0);
+            }
+        }
+
+        ClassTable {
+            ascii,
+            other,
+            classes,
+            table,
+        }
+    }
+
+    fn class_of(&self, c: char) -> usize {
+        if c.is_ascii() {
+            self.ascii[c as usize]
+        } else {
+            match self.other.binary_search_by_key(&c, |&(ch, _)| ch) {
+                Ok(i) => self.other[i].1,
+                Err(_) => 0,
+            }
+        }
+    }
+
+    fn next_state(&self, state: usize, c: char) -> usize {
+        self.table[state * self.classes + self.class_of(c)]
+    }
+}
+
+/// Buduje płaską tablicę przejść `[stan * 256 + bajt]` automatu prefiksowego
+/// dla wzorca traktowanego jako ciąg bajtów – ta sama konstrukcja co w
+/// [`build_prefix_automaton`], ale z wierszem tablicy zamiast mapy.
+pub(crate) fn build_byte_table(p: &[u8]) -> Vec<usize> {
+    let m = p.len();
+    let mut table = vec![0; (m + 1) * 256];
+    if m == 0 {
+        return table;
+    }
+    table[p[0] as usize] = 1;
+
+    let mut longest_prefix_sufix = 0;
+    for j in 1..m {
+        // Kopiujemy cały wiersz stanu longest_prefix_sufix.
+        let row = longest_prefix_sufix * 256;
+        table.copy_within(row..row + 256, j * 256);
+        table[j * 256 + p[j] as usize] = j + 1;
+        longest_prefix_sufix = table[row + p[j] as usize];
+    }
+    let row = longest_prefix_sufix * 256;
+    table.copy_within(row..row + 256, m * 256);
+
+    table
+}
+
+/// Dekoduje pierwszy znak UTF-8 z początku `bytes`. Niepoprawny bajt
+/// zwracany jest jako `None` o długości 1.
+pub(crate) fn decode_char(bytes: &[u8]) -> (Option<char>, usize) {
+    let window = &bytes[..bytes.len().min(4)];
+    let valid = match std::str::from_utf8(window) {
+        Ok(s) => s,
+        Err(e) => std::str::from_utf8(&window[..e.valid_up_to()]).unwrap_or(""),
+    };
+    match valid.chars().next() {
+        Some(c) => (Some(c), c.len_utf8()),
+        None => (None, 1),
+    }
+}
+
+/// Kolejne wystąpienie pustego wzorca: bieżąca granica znaku `*pos`, po czym
+/// `*pos` przesuwa się za kolejny znak, a po końcu tekstu ustawiane jest `*done`.
+pub(crate) fn next_empty_match(haystack: &[u8], pos: &mut usize, done: &mut bool) -> Option<usize> {
+    let i = *pos;
+    if i < haystack.len() {
+        *pos += decode_char(&haystack[i..]).1;
+    } else {
+        *done = true;
+    }
+    Some(i)
+}
+
+/// Wszystkie granice znaków w `haystack` (jak w [`decode_char`]), łącznie
+/// z końcem tekstu – wystąpienia pustego wzorca.
+pub(crate) fn char_boundaries(haystack: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    let (mut pos, mut done) = (0, false);
+    std::iter::from_fn(move || {
+        if done {
+            None
+        } else {
+            next_empty_match(haystack, &mut pos, &mut done)
+        }
+    })
+}
+
+#[derive(Debug, Clone)]
+enum Transitions {
+    HashMap,
+    ByteTable(Vec<usize>),
+    ClassTable(Box<ClassTable>),
+}
+
+/// Automat prefiksowy razem z informacjami o wzorcu potrzebnymi do raportowania
+/// pozycji wystąpień.
+#[derive(Debug, Clone)]
+pub struct PrefixAutomaton {
+    dfa: Vec<HashMap<char, usize>>,
+    transitions: Transitions,
+    // Długość wzorca w bajtach – dopasowany fragment tekstu jest identyczny ze
+    // wzorcem, więc ma tę samą długość w bajtach.
+    pattern_len: usize,
+}
+
+impl PrefixAutomaton {
+    /// Buduje automat dla wzorca `pattern` (patrz [`build_prefix_automaton`])
+    /// w reprezentacji [`Representation::HashMap`].
+    pub fn new(pattern: &str) -> Self {
+        Self::with_representation(pattern, Representation::HashMap)
+    }
+
+    /// Buduje automat dla wzorca `pattern` w wybranej reprezentacji.
+    pub fn with_representation(pattern: &str, representation: Representation) -> Self {
+        let dfa = build_prefix_automaton(pattern);
+        let transitions = match representation {
+            Representation::HashMap => Transitions::HashMap,
+            Representation::ByteTable => {
+                Transitions::ByteTable(build_byte_table(pattern.as_bytes()))
+            }
+            Representation::ClassTable => Transitions::ClassTable(Box::new(ClassTable::new(&dfa))),
+        };
+        PrefixAutomaton {
+            dfa,
+            transitions,
+            pattern_len: pattern.len(),
+        }
+    }
+
+    /// Reprezentacja wybrana przy budowie automatu.
+    pub fn representation(&self) -> Representation {
+        match self.transitions {
+            Transitions::HashMap => Representation::HashMap,
+            Transitions::ByteTable(_) => Representation::ByteTable,
+            Transitions::ClassTable(_) => Representation::ClassTable,
+        }
+    }
+
+    /// Tablica przejść automatu; stan `m` = `states().len() - 1` jest akceptujący.
+    pub fn states(&self) -> &[HashMap<char, usize>] {
+        &self.dfa
+    }
+
+    /// Liczba znaków wzorca, czyli numer stanu akceptującego.
+    pub fn m(&self) -> usize {
+        self.dfa.len() - 1
+    }
+
+    /// Automat w formacie DOT (patrz [`to_dot`]); stan `m` jest akceptujący.
+    pub fn to_dot(&self, options: DotOptions) -> String {
+        to_dot(&self.dfa, &[self.m()], options)
+    }
+
+    /// Tablica przejść do wypisania w terminalu (patrz [`transition_table`]).
+    /// Brak przejścia (`-`) oznacza powrót do stanu 0.
+    pub fn transition_table(&self) -> String {
+        transition_table(&self.dfa, &[self.m()])
+    }
+
+    /// Iterator po pozycjach (w bajtach) początków rozłącznych wystąpień wzorca,
+    /// wyszukiwanych od lewej, np. "aa" w "aaaa" daje 0 i 2.
+    pub fn find_non_overlapping_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> Matches<'a, 't> {
+        Matches::new(self, haystack.as_ref(), false)
+    }
+}
+
+/// Wszystkie wystąpienia, łącznie z nakładającymi się, np. "aa" w "aaa" daje
+/// 0 i 1. Po dopasowaniu automat kontynuuje ze stanu `m`, korzystając z przejść
+/// uzupełnionych dla tego stanu w [`build_prefix_automaton`].
+///
+/// Tekst nie musi być poprawnym UTF-8 (np. logi) – niepoprawne bajty nie pasują
+/// do żadnego znaku wzorca.
+impl Matcher for PrefixAutomaton {
+    type Iter<'a, 't> = Matches<'a, 't>;
+
+    fn compile(pattern: &str) -> Self {
+        PrefixAutomaton::new(pattern)
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(&'a self, haystack: &'t H) -> Matches<'a, 't> {
+        Matches::new(self, haystack.as_ref(), true)
+    }
+}
+
+/// Automat rozpoznaje teksty kończące się wzorcem; znaki bez przejścia
+/// prowadzą do stanu 0, więc nie ma stanu martwego.
+impl Automaton for PrefixAutomaton {
+    fn state_count(&self) -> usize {
+        self.dfa.len()
+    }
+
+    fn is_accepting(&self, state: usize) -> bool {
+        state == self.m()
+    }
+
+    fn next_state(&self, state: usize, c: char) -> Option<usize> {
+        Some(self.dfa[state].get(&c).copied().unwrap_or(0))
+    }
+
+    /// Każdy znak wzorca tworzy osobny przedział; pozostałe znaki zachowują
+    /// się jednakowo.
+    fn alphabet(&self) -> Vec<char> {
+        let mut starts = vec!['\0'];
+        for &c in self.dfa[0].keys() {
+            starts.push(c);
+            starts.extend((c as u32 + 1..=char::MAX as u32).find_map(char::from_u32));
+        }
+        starts.sort_unstable();
+        starts.dedup();
+        starts
+    }
+}
+
+/// Iterator po pozycjach wystąpień wzorca zwracany przez
+/// [`Matcher::find_iter`] i [`PrefixAutomaton::find_non_overlapping_iter`].
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+#[derive(Debug, Clone)]
+pub struct Matches<'a, 't> {
+    automaton: &'a PrefixAutomaton,
+    haystack: &'t [u8],
+    pos: usize,
+    state: usize,
+    overlapping: bool,
+    done: bool,
+}
+
+impl<'a, 't> Matches<'a, 't> {
+    fn new(automaton: &'a PrefixAutomaton, haystack: &'t [u8], overlapping: bool) -> Self {
+        Matches {
+            automaton,
+            haystack,
+            pos: 0,
+            state: 0,
+            overlapping,
+            done: false,
+        }
+    }
+
+    /// Wykonuje jedno przejście automatu i zwraca `true`, jeśli osiągnięto
+    /// stan akceptujący.
+    fn step(&mut self) -> bool {
+        let bytes = &self.haystack[self.pos..];
+        match &self.automaton.transitions {
+            Transitions::ByteTable(table) => {
+                self.state = table[self.state * 256 + bytes[0] as usize];
+                self.pos += 1;
+                self.state == self.automaton.pattern_len
+            }
+            Transitions::HashMap => {
+                let (c, len) = decode_char(bytes);
+                // Jeśli nie mamy zdefiniowanego przejścia dla danego znaku, przechodzimy do stanu 0.
+                self.state = c
+                    .and_then(|c| self.automaton.dfa[self.state].get(&c).copied())
+                    .unwrap_or(0);
+                self.pos += len;
+                self.state == self.automaton.m()
+            }
+            Transitions::ClassTable(classes) => {
+                let (c, len) = decode_char(bytes);
+                self.state = c.map_or(0, |c| classes.next_state(self.state, c));
+                self.pos += len;
+                self.state == self.automaton.m()
+            }
+        }
+    }
+}
+
+impl Iterator for Matches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        if self.automaton.pattern_len == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        while self.pos < self.haystack.len() {
+            if self.step() {
+                // W trybie rozłącznym kolejne wystąpienie musi zacząć się za bieżącym.
+                if !self.overlapping {
+                    self.state = 0;
+                }
+                return Some(self.pos - self.automaton.pattern_len);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
@@ -0,0 +1,245 @@
+use super::automaton::next_empty_match;
+use super::matcher::Matcher;
+
+/// Tablica złego znaku: `bc[b]` to odległość ostatniego wystąpienia bajtu `b`
+/// w `p[0..m-1]` od końca wzorca, albo `m`, jeśli `b` tam nie występuje.
+/// Wymaga niepustego wzorca.
+fn bad_character_table(p: &[u8]) -> [usize; 256] {
+    let m = p.len();
+    let mut bc = [m; 256];
+    for (i, &b) in p[..m - 1].iter().enumerate() {
+        bc[b as usize] = m - 1 - i;
+    }
+    bc
+}
+
+/// `suff[i]` – długość najdłuższego wspólnego sufiksu `p[0..=i]` i całego `p`,
+/// liczona w czasie liniowym (wariant Charrasa–Lecroqa). Wymaga niepustego wzorca.
+fn suffixes(p: &[u8]) -> Vec<usize> {
+    let m = p.len() as isize;
+    let mut suff = vec![0; p.len()];
+    suff[p.len() - 1] = p.len();
+    // [g+1, f] to okno najbardziej na lewo sięgającego znalezionego sufiksu.
+    let mut g = m - 1;
+    let mut f = m - 1;
+    for i in (0..m - 1).rev() {
+        if i > g && (suff[(i + m - 1 - f) as usize] as isize) < i - g {
+            suff[i as usize] = suff[(i + m - 1 - f) as usize];
+        } else {
+            g = g.min(i);
+            f = i;
+            while g >= 0 && p[g as usize] == p[(g + m - 1 - f) as usize] {
+                g -= 1;
+            }
+            suff[i as usize] = (f - g) as usize;
+        }
+    }
+    suff
+}
+
+/// Tablica dobrego sufiksu: `gs[j]` to bezpieczne przesunięcie, gdy
+/// niedopasowanie wystąpiło na pozycji `j` wzorca (a `p[j+1..]` pasuje);
+/// `gs[0]` to przesunięcie po pełnym dopasowaniu. Wymaga niepustego wzorca.
+fn good_suffix_table(p: &[u8]) -> Vec<usize> {
+    let m = p.len();
+    let suff = suffixes(p);
+    let mut gs = vec![m; m];
+
+    // Przypadek 2: dopasowany sufiks zawiera brzeg całego wzorca.
+    let mut j = 0;
+    for i in (0..m).rev() {
+        if suff[i] == i + 1 {
+            while j < m - 1 - i {
+                if gs[j] == m {
+                    gs[j] = m - 1 - i;
+                }
+                j += 1;
+            }
+        }
+    }
+    // Przypadek 1: dopasowany sufiks występuje we wzorcu wcześniej.
+    for i in 0..m - 1 {
+        gs[m - 1 - suff[i]] = m - 1 - i;
+    }
+    gs
+}
+
+/// Wzorzec przygotowany do wyszukiwania algorytmem Boyera–Moore'a
+/// (heurystyki złego znaku i dobrego sufiksu).
+///
+/// Okno porównywane jest od prawej do lewej, a przesunięcia pozwalają pominąć
+/// część tekstu – dla długich wzorców nad dużym alfabetem algorytm czyta
+/// średnio O(n / m) znaków. Wyszukiwanie odbywa się na bajtach UTF-8.
+#[derive(Debug, Clone)]
+pub struct BoyerMoore {
+    pattern: Vec<u8>,
+    bad_character: [usize; 256],
+    good_suffix: Vec<usize>,
+}
+
+impl BoyerMoore {
+    /// Przygotowuje wzorzec `pattern` i obie tablice przesunięć.
+    pub fn new(pattern: &str) -> Self {
+        let pattern = pattern.as_bytes().to_vec();
+        let (bad_character, good_suffix) = if pattern.is_empty() {
+            ([0; 256], Vec::new())
+        } else {
+            (bad_character_table(&pattern), good_suffix_table(&pattern))
+        };
+        BoyerMoore {
+            pattern,
+            bad_character,
+            good_suffix,
+        }
+    }
+}
+
+/// Wszystkie wystąpienia, łącznie z nakładającymi się.
+impl Matcher for BoyerMoore {
+    type Iter<'a, 't> = BoyerMooreMatches<'a, 't>;
+
+    fn compile(pattern: &str) -> Self {
+        BoyerMoore::new(pattern)
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> BoyerMooreMatches<'a, 't> {
+        BoyerMooreMatches {
+            bm: self,
+            haystack: haystack.as_ref(),
+            pos: 0,
+            done: false,
+        }
+    }
+}
+
+/// Iterator po pozycjach wystąpień zwracany przez [`BoyerMoore`].
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+#[derive(Debug, Clone)]
+pub struct BoyerMooreMatches<'a, 't> {
+    bm: &'a BoyerMoore,
+    haystack: &'t [u8],
+    pos: usize, // początek bieżącego okna
+    done: bool,
+}
+
+impl Iterator for BoyerMooreMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let p = &self.bm.pattern;
+        let m = p.len();
+        if m == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        let t = self.haystack;
+        while self.pos + m <= t.len() {
+            let s = self.pos;
+            // Porównujemy od prawej; j – liczba jeszcze niesprawdzonych znaków.
+            let mut j = m;
+            while j > 0 && p[j - 1] == t[s + j - 1] {
+                j -= 1;
+            }
+            if j == 0 {
+                self.pos += self.bm.good_suffix[0];
+                return Some(s);
+            }
+            // Niedopasowanie na pozycji j-1 wzorca.
+            let bad = t[s + j - 1] as usize;
+            let shift_bc = (self.bm.bad_character[bad] + j).saturating_sub(m);
+            self.pos += self.bm.good_suffix[j - 1].max(shift_bc);
+        }
+        self.done = true;
+        None
+    }
+}
+
+/// Wzorzec przygotowany do wyszukiwania algorytmem Boyera–Moore'a–Horspoola.
+///
+/// Uproszczony Boyer–Moore: okno przesuwa się o wartość tablicy złego znaku
+/// dla ostatniego bajtu okna, bez tablicy dobrego sufiksu.
+#[derive(Debug, Clone)]
+pub struct Horspool {
+    pattern: Vec<u8>,
+    bad_character: [usize; 256],
+}
+
+impl Horspool {
+    /// Przygotowuje wzorzec `pattern` i tablicę przesunięć.
+    pub fn new(pattern: &str) -> Self {
+        let pattern = pattern.as_bytes().to_vec();
+        let bad_character = if pattern.is_empty() {
+            [0; 256]
+        } else {
+            bad_character_table(&pattern)
+        };
+        Horspool {
+            pattern,
+            bad_character,
+        }
+    }
+}
+
+/// Wszystkie wystąpienia, łącznie z nakładającymi się.
+impl Matcher for Horspool {
+    type Iter<'a, 't> = HorspoolMatches<'a, 't>;
+
+    fn compile(pattern: &str) -> Self {
+        Horspool::new(pattern)
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> HorspoolMatches<'a, 't> {
+        HorspoolMatches {
+            horspool: self,
+            haystack: haystack.as_ref(),
+            pos: 0,
+            done: false,
+        }
+    }
+}
+
+/// Iterator po pozycjach wystąpień zwracany przez [`Horspool`].
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+#[derive(Debug, Clone)]
+pub struct HorspoolMatches<'a, 't> {
+    horspool: &'a Horspool,
+    haystack: &'t [u8],
+    pos: usize, // początek bieżącego okna
+    done: bool,
+}
+
+impl Iterator for HorspoolMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let p = &self.horspool.pattern;
+        let m = p.len();
+        if m == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        let t = self.haystack;
+        while self.pos + m <= t.len() {
+            let s = self.pos;
+            let last = t[s + m - 1];
+            self.pos += self.horspool.bad_character[last as usize];
+            if p[m - 1] == last && p[..m - 1] == t[s..s + m - 1] {
+                return Some(s);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
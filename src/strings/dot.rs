@@ -0,0 +1,149 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+/// Opcje eksportu automatu do formatu DOT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DotOptions {
+    /// Pomija krawędzie wracające do stanu 0 z innych stanów (także krawędzie
+    /// porażki do korzenia trie). W automacie prefiksowym to większość
+    /// przejść, a rysunek bez nich czyta się znacznie łatwiej.
+    pub hide_back_edges: bool,
+}
+
+/// Znak jako tekst czytelny w terminalu (znaki sterujące jako `\n`, `\t`, …).
+fn display_char(c: char) -> String {
+    if c.is_control() {
+        c.escape_debug().to_string()
+    } else {
+        c.to_string()
+    }
+}
+
+/// Tekst jako zawartość napisu w cudzysłowach w formacie DOT.
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Zapisuje automat w formacie DOT (Graphviz), np. do wyrenderowania
+/// poleceniem `dot -Tsvg`.
+///
+/// Stan 0 jest początkowy (wskazuje na niego strzałka), stany z `accepting`
+/// rysowane są podwójnym okręgiem, a przejścia między tą samą parą stanów
+/// łączone są w jedną krawędź z listą znaków. Kolejność stanów, krawędzi
+/// i znaków jest stała, więc wynik nadaje się do porównywania.
+pub fn to_dot(
+    transitions: &[HashMap<char, usize>],
+    accepting: &[usize],
+    options: DotOptions,
+) -> String {
+    write_dot(transitions, accepting, &[], options)
+}
+
+/// Wspólna część eksportu; `dashed` to dodatkowe krawędzie bez etykiet
+/// (np. krawędzie porażki w trie).
+pub(crate) fn write_dot(
+    transitions: &[HashMap<char, usize>],
+    accepting: &[usize],
+    dashed: &[(usize, usize)],
+    options: DotOptions,
+) -> String {
+    let hidden = |from: usize, to: usize| options.hide_back_edges && to == 0 && from != 0;
+    let mut dot = String::new();
+    writeln!(dot, "digraph automat {{").unwrap();
+    writeln!(dot, "    rankdir=LR;").unwrap();
+    writeln!(dot, "    node [shape=circle];").unwrap();
+    writeln!(dot, "    start [shape=point];").unwrap();
+    writeln!(dot, "    start -> 0;").unwrap();
+    if options.hide_back_edges {
+        writeln!(
+            dot,
+            "    // pominięto krawędzie prowadzące z powrotem do stanu 0"
+        )
+        .unwrap();
+    }
+    let accepting: BTreeSet<usize> = accepting.iter().copied().collect();
+    for q in 0..transitions.len() {
+        let shape = if accepting.contains(&q) {
+            "doublecircle"
+        } else {
+            "circle"
+        };
+        writeln!(dot, "    {} [shape={}];", q, shape).unwrap();
+    }
+    for (q, row) in transitions.iter().enumerate() {
+        // Znaki pogrupowane według stanu docelowego.
+        let mut edges: BTreeMap<usize, Vec<char>> = BTreeMap::new();
+        for (&c, &to) in row {
+            if !hidden(q, to) {
+                edges.entry(to).or_default().push(c);
+            }
+        }
+        for (to, mut chars) in edges {
+            chars.sort_unstable();
+            let label: Vec<String> = chars.into_iter().map(display_char).collect();
+            writeln!(
+                dot,
+                "    {} -> {} [label=\"{}\"];",
+                q,
+                to,
+                dot_escape(&label.join(","))
+            )
+            .unwrap();
+        }
+    }
+    for &(from, to) in dashed {
+        if !hidden(from, to) {
+            writeln!(dot, "    {} -> {} [style=dashed];", from, to).unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Tablica przejść jako tekst do wypisania w terminalu: wiersz na stan,
+/// kolumna na znak występujący w którymkolwiek przejściu. Stan początkowy
+/// oznaczony jest `→`, akceptujące `*`, a brak przejścia `-`.
+pub fn transition_table(transitions: &[HashMap<char, usize>], accepting: &[usize]) -> String {
+    let alphabet: BTreeSet<char> = transitions.iter().flat_map(|t| t.keys().copied()).collect();
+    let header: Vec<String> = alphabet.iter().map(|&c| display_char(c)).collect();
+    let rows: Vec<Vec<String>> = transitions
+        .iter()
+        .map(|row| {
+            alphabet
+                .iter()
+                .map(|c| row.get(c).map_or("-".to_string(), usize::to_string))
+                .collect()
+        })
+        .collect();
+
+    let state_width = transitions.len().saturating_sub(1).to_string().len();
+    let widths: Vec<usize> = (0..alphabet.len())
+        .map(|k| {
+            rows.iter()
+                .map(|r| r[k].chars().count())
+                .chain(std::iter::once(header[k].chars().count()))
+                .max()
+                .unwrap_or(1)
+        })
+        .collect();
+    let line = |marker: &str, state: &str, cells: &[String]| {
+        let mut s = format!("{} {:>w$} |", marker, state, w = state_width);
+        for (cell, &w) in cells.iter().zip(&widths) {
+            write!(s, " {:>w$}", cell, w = w).unwrap();
+        }
+        s.push('\n');
+        s
+    };
+
+    let mut table = line("  ", "", &header);
+    for (q, row) in rows.iter().enumerate() {
+        let marker = match (q == 0, accepting.contains(&q)) {
+            (true, true) => "→*",
+            (true, false) => "→ ",
+            (false, true) => " *",
+            (false, false) => "  ",
+        };
+        table.push_str(&line(marker, &q.to_string(), row));
+    }
+    table
+}
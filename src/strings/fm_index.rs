@@ -0,0 +1,462 @@
+use std::io::{self, ErrorKind, Read, Write};
+use std::ops::Range;
+
+use super::suffix_array::suffix_array;
+use crate::{Error, Result};
+
+/// Domyślny odstęp między zapamiętanymi licznikami wystąpień (tablica occ).
+pub const DEFAULT_OCC_RATE: usize = 64;
+/// Największy dopuszczalny odstęp między licznikami wystąpień. Ogranicza
+/// koszt zapytania o rangę do stałej liczby porównań.
+pub const MAX_OCC_RATE: usize = 256;
+/// Domyślny odstęp (w pozycjach tekstu) między zapamiętanymi wartościami
+/// tablicy sufiksowej.
+pub const DEFAULT_SA_RATE: usize = 32;
+
+/// Nagłówek pliku z zapisanym indeksem.
+const MAGIC: &[u8; 4] = b"FMI1";
+
+/// Transformata Burrowsa–Wheelera tekstu `text$`, gdzie `$` to znacznik końca
+/// mniejszy od wszystkich symboli. Zwraca BWT bez znacznika (`n` bajtów)
+/// oraz pozycję, na której znacznik by stał (tzw. indeks pierwotny).
+pub fn bwt(text: &[u8]) -> (Vec<u8>, usize) {
+    let mut wynik = Vec::with_capacity(text.len());
+    let mut primary = 0;
+    // Wiersz 0 to sufiks pusty `$`; jego poprzednik to ostatni symbol tekstu.
+    let rows = std::iter::once(text.len()).chain(suffix_array(text));
+    for (row, i) in rows.enumerate() {
+        if i == 0 {
+            primary = row;
+        } else {
+            wynik.push(text[i - 1]);
+        }
+    }
+    (wynik, primary)
+}
+
+/// Odwrotna transformata Burrowsa–Wheelera (patrz [`bwt`]) w czasie O(n):
+/// odtwarza tekst od końca, przechodząc odwzorowaniem LF od wiersza `$`.
+///
+/// Zwraca [`Error::InvalidParameter`], jeśli `primary > bwt.len()`.
+pub fn inverse_bwt(bwt: &[u8], primary: usize) -> Result<Vec<u8>> {
+    let n = bwt.len();
+    if primary > n {
+        return Err(Error::InvalidParameter("indeks pierwotny poza BWT"));
+    }
+    // Symbol wiersza `row` pełnej BWT (z `$` na pozycji `primary`).
+    let symbol = |row: usize| {
+        if row < primary {
+            bwt[row]
+        } else {
+            bwt[row - 1]
+        }
+    };
+
+    let mut c = [0usize; 257];
+    for &b in bwt {
+        c[b as usize + 1] += 1;
+    }
+    for b in 0..256 {
+        c[b + 1] += c[b];
+    }
+    // lf[row] = 1 (za `$`) + liczba mniejszych symboli + liczba tych samych wcześniej.
+    let mut seen = [0usize; 256];
+    let mut lf = vec![0; n + 1];
+    for (row, lf) in lf.iter_mut().enumerate() {
+        if row != primary {
+            let b = symbol(row) as usize;
+            *lf = 1 + c[b] + seen[b];
+            seen[b] += 1;
+        }
+    }
+
+    let mut wynik = vec![0; n];
+    let mut row = 0;
+    for k in (0..n).rev() {
+        wynik[k] = symbol(row);
+        row = lf[row];
+    }
+    Ok(wynik)
+}
+
+/// FM-indeks: skompresowany indeks pełnotekstowy oparty na BWT.
+///
+/// Przechowuje BWT tekstu, tablicę `C` (liczba symboli mniejszych od danego),
+/// liczniki wystąpień symboli w BWT co `occ_rate` pozycji oraz wartości
+/// tablicy sufiksowej dla pozycji tekstu podzielnych przez `sa_rate`.
+/// Zliczanie wystąpień wzorca (wyszukiwanie wsteczne) działa w czasie
+/// O(m log σ), bo `occ_rate` jest ograniczone przez [`MAX_OCC_RATE`],
+/// a lokalizacja każdego wystąpienia wymaga dodatkowo co najwyżej
+/// `sa_rate` kroków odwzorowania LF.
+///
+/// Indeksować można bajty lub dowolne symbole konwertowalne do `u8`, np.
+/// [`Nucleotide`](crate::dna::Nucleotide). Indeks można zapisać na dysk
+/// ([`write_to`](Self::write_to)) i wczytać ([`read_from`](Self::read_from)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FmIndex {
+    bwt: Vec<u8>,
+    primary: usize,
+    occ_rate: usize,
+    sa_rate: usize,
+    /// Posortowane symbole występujące w tekście; kod symbolu to jego indeks.
+    alphabet: Vec<u8>,
+    /// `c[code]` – 1 (znacznik `$`) + liczba symboli mniejszych od `alphabet[code]`.
+    c: Vec<usize>,
+    /// `occ[k * σ + code]` – wystąpienia `alphabet[code]` w `bwt` przed wierszem `k * occ_rate`.
+    occ: Vec<usize>,
+    /// Bity wierszy, dla których zapamiętano wartość tablicy sufiksowej.
+    marks: Vec<u64>,
+    /// Liczba zaznaczonych wierszy przed każdym słowem `marks`.
+    mark_rank: Vec<usize>,
+    /// Zapamiętane wartości tablicy sufiksowej w kolejności wierszy.
+    samples: Vec<usize>,
+}
+
+impl FmIndex {
+    /// Buduje indeks z domyślnym próbkowaniem.
+    pub fn new<T: Copy + Into<u8>>(text: &[T]) -> Self {
+        Self::with_sampling(text, DEFAULT_OCC_RATE, DEFAULT_SA_RATE)
+            .expect("domyślne parametry próbkowania są dodatnie")
+    }
+
+    /// Buduje indeks, zapamiętując liczniki wystąpień co `occ_rate` wierszy
+    /// BWT i wartości tablicy sufiksowej co `sa_rate` pozycji tekstu.
+    /// Większe odstępy to mniej pamięci kosztem wolniejszych zapytań: każdy
+    /// krok wyszukiwania wstecznego przegląda do `occ_rate` bajtów BWT.
+    ///
+    /// Zwraca [`Error::InvalidParameter`], jeśli któryś odstęp jest zerem
+    /// albo `occ_rate` przekracza [`MAX_OCC_RATE`].
+    pub fn with_sampling<T: Copy + Into<u8>>(
+        text: &[T],
+        occ_rate: usize,
+        sa_rate: usize,
+    ) -> Result<Self> {
+        if occ_rate == 0 || sa_rate == 0 {
+            return Err(Error::InvalidParameter(
+                "odstęp próbkowania musi być dodatni",
+            ));
+        }
+        if occ_rate > MAX_OCC_RATE {
+            return Err(Error::InvalidParameter(
+                "occ_rate nie może przekraczać MAX_OCC_RATE",
+            ));
+        }
+        let text: Vec<u8> = text.iter().map(|&s| s.into()).collect();
+        let sa: Vec<usize> = std::iter::once(text.len())
+            .chain(suffix_array(&text))
+            .collect();
+
+        let mut bwt = Vec::with_capacity(text.len());
+        let mut primary = 0;
+        let mut marks = vec![0u64; sa.len().div_ceil(64)];
+        let mut samples = Vec::new();
+        for (row, &i) in sa.iter().enumerate() {
+            if i == 0 {
+                primary = row;
+            } else {
+                bwt.push(text[i - 1]);
+            }
+            if i.is_multiple_of(sa_rate) {
+                marks[row / 64] |= 1 << (row % 64);
+                samples.push(i);
+            }
+        }
+        Ok(Self::from_parts(
+            bwt, primary, occ_rate, sa_rate, marks, samples,
+        ))
+    }
+
+    /// Uzupełnia tablice pochodne (alfabet, `C`, occ, rangi znaczników).
+    fn from_parts(
+        bwt: Vec<u8>,
+        primary: usize,
+        occ_rate: usize,
+        sa_rate: usize,
+        marks: Vec<u64>,
+        samples: Vec<usize>,
+    ) -> Self {
+        let mut counts = [0usize; 256];
+        for &b in &bwt {
+            counts[b as usize] += 1;
+        }
+        let alphabet: Vec<u8> = (0..=255u8).filter(|&b| counts[b as usize] > 0).collect();
+        let sigma = alphabet.len();
+        let mut c = Vec::with_capacity(sigma);
+        let mut total = 1;
+        for &b in &alphabet {
+            c.push(total);
+            total += counts[b as usize];
+        }
+
+        let rows = bwt.len() + 1;
+        let mut occ = vec![0; (rows / occ_rate + 1) * sigma];
+        let mut running = vec![0; sigma];
+        for row in 0..=rows {
+            if row % occ_rate == 0 {
+                let k = row / occ_rate;
+                occ[k * sigma..(k + 1) * sigma].copy_from_slice(&running);
+            }
+            if row < rows && row != primary {
+                let b = bwt[if row < primary { row } else { row - 1 }];
+                running[alphabet.binary_search(&b).unwrap()] += 1;
+            }
+        }
+
+        let mut mark_rank = Vec::with_capacity(marks.len());
+        let mut total = 0;
+        for &w in &marks {
+            mark_rank.push(total);
+            total += w.count_ones() as usize;
+        }
+
+        FmIndex {
+            bwt,
+            primary,
+            occ_rate,
+            sa_rate,
+            alphabet,
+            c,
+            occ,
+            marks,
+            mark_rank,
+            samples,
+        }
+    }
+
+    /// Długość zaindeksowanego tekstu.
+    pub fn len(&self) -> usize {
+        self.bwt.len()
+    }
+
+    /// Czy zaindeksowany tekst jest pusty.
+    pub fn is_empty(&self) -> bool {
+        self.bwt.is_empty()
+    }
+
+    /// BWT tekstu bez znacznika końca i indeks pierwotny (patrz [`bwt`]).
+    pub fn bwt(&self) -> (&[u8], usize) {
+        (&self.bwt, self.primary)
+    }
+
+    /// Symbol w wierszu `row` pełnej BWT; `None` dla znacznika `$`.
+    fn symbol(&self, row: usize) -> Option<u8> {
+        match row.cmp(&self.primary) {
+            std::cmp::Ordering::Less => Some(self.bwt[row]),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(self.bwt[row - 1]),
+        }
+    }
+
+    /// Liczba wystąpień symbolu o kodzie `code` w wierszach `0..row` BWT:
+    /// zapamiętany licznik plus zliczenie w spójnym fragmencie BWT długości
+    /// mniejszej niż `occ_rate` ≤ [`MAX_OCC_RATE`], czyli czas O(1).
+    fn rank(&self, code: usize, row: usize) -> usize {
+        let k = row / self.occ_rate;
+        let b = self.alphabet[code];
+        // Indeks w `bwt` (bez znacznika) pierwszego wiersza nie mniejszego niż `row`.
+        let index = |row: usize| row - usize::from(row > self.primary);
+        let extra = self.bwt[index(k * self.occ_rate)..index(row)]
+            .iter()
+            .filter(|&&x| x == b)
+            .count();
+        self.occ[k * self.alphabet.len() + code] + extra
+    }
+
+    /// Odwzorowanie LF: wiersz sufiksu zaczynającego się o jedną pozycję
+    /// wcześniej niż sufiks wiersza `row` (nie dotyczy wiersza znacznika).
+    fn lf(&self, row: usize) -> usize {
+        let b = self
+            .symbol(row)
+            .expect("wiersz znacznika nie ma poprzednika");
+        let code = self.alphabet.binary_search(&b).unwrap();
+        self.c[code] + self.rank(code, row)
+    }
+
+    /// Wyszukiwanie wsteczne: przedział wierszy (tablicy sufiksowej `text$`)
+    /// z sufiksami zaczynającymi się od `pattern`. Przetwarza wzorzec od
+    /// końca, zawężając przedział odwzorowaniem LF. Każdy symbol wzorca to
+    /// wyszukanie jego kodu w alfabecie i dwa zapytania o rangę w czasie O(1),
+    /// więc razem O(m log σ).
+    pub fn backward_search<T: Copy + Into<u8>>(&self, pattern: &[T]) -> Range<usize> {
+        let (mut lo, mut hi) = (0, self.len() + 1);
+        for &s in pattern.iter().rev() {
+            let Ok(code) = self.alphabet.binary_search(&s.into()) else {
+                return 0..0;
+            };
+            lo = self.c[code] + self.rank(code, lo);
+            hi = self.c[code] + self.rank(code, hi);
+            if lo >= hi {
+                return 0..0;
+            }
+        }
+        lo..hi
+    }
+
+    /// Liczba (także nakładających się) wystąpień `pattern`.
+    /// Pusty wzorzec występuje `n + 1` razy.
+    pub fn count<T: Copy + Into<u8>>(&self, pattern: &[T]) -> usize {
+        self.backward_search(pattern).len()
+    }
+
+    /// Posortowane pozycje wszystkich wystąpień `pattern`. Pozycję wiersza
+    /// wyznacza przejście odwzorowaniem LF do najbliższego zapamiętanego
+    /// wiersza (co najwyżej `sa_rate` kroków) plus liczba wykonanych kroków.
+    /// Pusty wzorzec występuje na każdej pozycji 0..=n.
+    pub fn locate<T: Copy + Into<u8>>(&self, pattern: &[T]) -> Vec<usize> {
+        let mut wynik: Vec<usize> = self
+            .backward_search(pattern)
+            .map(|row| self.suffix_at(row))
+            .collect();
+        wynik.sort_unstable();
+        wynik
+    }
+
+    /// Wartość tablicy sufiksowej w wierszu `row`.
+    fn suffix_at(&self, mut row: usize) -> usize {
+        let mut steps = 0;
+        loop {
+            let (word, bit) = (row / 64, row % 64);
+            if self.marks[word] >> bit & 1 == 1 {
+                let below = (self.marks[word] & ((1u64 << bit) - 1)).count_ones() as usize;
+                return self.samples[self.mark_rank[word] + below] + steps;
+            }
+            row = self.lf(row);
+            steps += 1;
+        }
+    }
+
+    /// Odtwarza zaindeksowany tekst (odwrotna BWT na próbkowanych licznikach).
+    pub fn text(&self) -> Vec<u8> {
+        let n = self.len();
+        let mut wynik = vec![0; n];
+        let mut row = 0;
+        for k in (0..n).rev() {
+            wynik[k] = self
+                .symbol(row)
+                .expect("znacznik występuje tylko na początku tekstu");
+            row = self.lf(row);
+        }
+        wynik
+    }
+
+    /// Zapisuje indeks w formacie binarnym: nagłówek `FMI1`, parametry i
+    /// długości jako `u64` little-endian, BWT, bity znaczników i próbki
+    /// tablicy sufiksowej. Tablice pochodne są odtwarzane przy wczytywaniu.
+    pub fn write_to<W: Write>(&self, mut w: W) -> io::Result<()> {
+        w.write_all(MAGIC)?;
+        for v in [
+            self.bwt.len(),
+            self.primary,
+            self.occ_rate,
+            self.sa_rate,
+            self.samples.len(),
+        ] {
+            w.write_all(&(v as u64).to_le_bytes())?;
+        }
+        w.write_all(&self.bwt)?;
+        for &m in &self.marks {
+            w.write_all(&m.to_le_bytes())?;
+        }
+        for &s in &self.samples {
+            w.write_all(&(s as u64).to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Wczytuje indeks zapisany przez [`write_to`](Self::write_to).
+    ///
+    /// Zwraca błąd [`ErrorKind::UnexpectedEof`] dla uciętego pliku i
+    /// [`ErrorKind::InvalidData`] dla niepoprawnego, także takiego, którego
+    /// BWT lub próbki nie pochodzą z żadnego tekstu (sprawdzane jednym
+    /// przejściem odwzorowaniem LF, w czasie O(n)). Długości z
+    /// nagłówka nie są używane do rezerwowania pamięci przed wczytaniem danych.
+    pub fn read_from<R: Read>(mut r: R) -> io::Result<Self> {
+        let invalid = |msg: &str| io::Error::new(ErrorKind::InvalidData, msg.to_string());
+        let mut magic = [0; 4];
+        r.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("to nie jest plik FM-indeksu"));
+        }
+        let read_u64 = |r: &mut R| -> io::Result<usize> {
+            let mut buf = [0; 8];
+            r.read_exact(&mut buf)?;
+            usize::try_from(u64::from_le_bytes(buf)).map_err(|_| invalid("za duża wartość"))
+        };
+        let n = read_u64(&mut r)?;
+        let primary = read_u64(&mut r)?;
+        let occ_rate = read_u64(&mut r)?;
+        let sa_rate = read_u64(&mut r)?;
+        let sample_count = read_u64(&mut r)?;
+        let rows = n
+            .checked_add(1)
+            .ok_or_else(|| invalid("za duża długość tekstu"))?;
+        if primary > n
+            || occ_rate == 0
+            || occ_rate > MAX_OCC_RATE
+            || sa_rate == 0
+            || sample_count > rows
+        {
+            return Err(invalid("niepoprawne parametry indeksu"));
+        }
+
+        // Bufor rośnie razem z faktycznie wczytanymi danymi.
+        let mut bwt = Vec::new();
+        r.by_ref().take(n as u64).read_to_end(&mut bwt)?;
+        if bwt.len() != n {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "ucięta BWT"));
+        }
+        let mut marks = Vec::with_capacity(rows.div_ceil(64));
+        for _ in 0..rows.div_ceil(64) {
+            let mut buf = [0; 8];
+            r.read_exact(&mut buf)?;
+            marks.push(u64::from_le_bytes(buf));
+        }
+        let mut samples = Vec::with_capacity(sample_count);
+        for _ in 0..sample_count {
+            samples.push(read_u64(&mut r)?);
+        }
+        // Bity za ostatnim wierszem muszą być zerami.
+        if rows % 64 != 0 && marks[rows / 64] >> (rows % 64) != 0 {
+            return Err(invalid("zaznaczony wiersz poza BWT"));
+        }
+        let marked: usize = marks.iter().map(|w| w.count_ones() as usize).sum();
+        if marked != sample_count {
+            return Err(invalid("niespójne próbki tablicy sufiksowej"));
+        }
+        let index = Self::from_parts(bwt, primary, occ_rate, sa_rate, marks, samples);
+        if !index.is_consistent() {
+            return Err(invalid("BWT i próbki nie pochodzą z żadnego tekstu"));
+        }
+        Ok(index)
+    }
+
+    /// Czy odwzorowanie LF jest jednym cyklem przez wszystkie n + 1 wierszy
+    /// (czyli BWT odwraca się do tekstu), a zapamiętane wiersze to dokładnie
+    /// pozycje podzielne przez `sa_rate` z poprawnymi wartościami. Gwarantuje,
+    /// że [`text`](Self::text) i [`locate`](Self::locate) się zatrzymują.
+    fn is_consistent(&self) -> bool {
+        let n = self.len();
+        // Wiersz 0 to sufiks `$`, czyli pozycja n; każdy krok LF cofa o jeden.
+        let mut row = 0;
+        for pos in (0..=n).rev() {
+            if (row == self.primary) != (pos == 0) {
+                return false;
+            }
+            let (word, bit) = (row / 64, row % 64);
+            let is_marked = self.marks[word] >> bit & 1 == 1;
+            if is_marked != pos.is_multiple_of(self.sa_rate) {
+                return false;
+            }
+            if is_marked {
+                let below = (self.marks[word] & ((1u64 << bit) - 1)).count_ones() as usize;
+                if self.samples[self.mark_rank[word] + below] != pos {
+                    return false;
+                }
+            }
+            if pos > 0 {
+                row = self.lf(row);
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,309 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::{Error, Result};
+
+/// Liczba pierwsza Mersenne'a 2^61 - 1 – moduł haszowania wielomianowego.
+/// Redukcja modulo 2^61 - 1 sprowadza się do przesunięć i dodawania.
+pub const MERSENNE_61: u64 = (1 << 61) - 1;
+
+/// Największa liczba pierwsza mniejsza od 2^62 – drugi moduł w
+/// [`RollingHashIndex`].
+pub const PRIME_62: u64 = (1 << 62) - 57;
+
+/// `a * b mod (2^61 - 1)` dla `a, b < 2^61 - 1`.
+pub fn mul_mod(a: u64, b: u64) -> u64 {
+    let x = a as u128 * b as u128;
+    // x = hi * 2^61 + lo, a 2^61 ≡ 1, więc x ≡ hi + lo.
+    let r = (x as u64 & MERSENNE_61) + (x >> 61) as u64;
+    if r >= MERSENNE_61 { r - MERSENNE_61 } else { r }
+}
+
+/// `a + b mod (2^61 - 1)` dla `a, b < 2^61 - 1`.
+pub fn add_mod(a: u64, b: u64) -> u64 {
+    let r = a + b;
+    if r >= MERSENNE_61 { r - MERSENNE_61 } else { r }
+}
+
+/// `a - b mod (2^61 - 1)` dla `a, b < 2^61 - 1`.
+pub fn sub_mod(a: u64, b: u64) -> u64 {
+    if a >= b { a - b } else { a + MERSENNE_61 - b }
+}
+
+/// Wielomianowa funkcja haszująca modulo liczba pierwsza `p` (domyślnie
+/// 2^61 - 1) o podstawie `base`:
+///   h(s) = s[0]·base^(m-1) + s[1]·base^(m-2) + … + s[m-1]  (mod p).
+///
+/// Symbolami mogą być bajty, znaki lub dowolne liczby mniejsze od `p`.
+/// Dla dwóch różnych ciągów długości m różnica haszy jest niezerowym
+/// wielomianem stopnia < m zmiennej `base`, więc przy podstawie losowanej
+/// jednostajnie z [0, p) kolidują z prawdopodobieństwem co najwyżej
+/// (m - 1) / p, niezależnie od treści ciągów.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolyHash {
+    base: u64,
+    modulus: u64,
+}
+
+impl PolyHash {
+    /// Funkcja haszująca modulo 2^61 - 1 o podanej podstawie (branej modulo
+    /// 2^61 - 1).
+    pub fn new(base: u64) -> Self {
+        PolyHash {
+            base: base % MERSENNE_61,
+            modulus: MERSENNE_61,
+        }
+    }
+
+    /// Funkcja haszująca modulo `modulus` o podanej podstawie (branej modulo
+    /// `modulus`). Ograniczenie prawdopodobieństwa kolizji z opisu typu
+    /// wymaga, by moduł był liczbą pierwszą.
+    ///
+    /// Zwraca [`Error::InvalidParameter`], jeśli `modulus` < 2 lub
+    /// `modulus` ≥ 2^63 (suma dwóch reszt musi mieścić się w `u64`).
+    pub fn with_modulus(base: u64, modulus: u64) -> Result<Self> {
+        if !(2..1 << 63).contains(&modulus) {
+            return Err(Error::InvalidParameter("moduł musi należeć do [2, 2^63)"));
+        }
+        Ok(PolyHash {
+            base: base % modulus,
+            modulus,
+        })
+    }
+
+    /// Funkcja haszująca modulo 2^61 - 1 z podstawą losowaną jednostajnie
+    /// z [0, 2^61 - 1).
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        PolyHash {
+            base: rng.random_range(0..MERSENNE_61),
+            modulus: MERSENNE_61,
+        }
+    }
+
+    /// Jak [`random`](Self::random), ale modulo `modulus` (patrz
+    /// [`with_modulus`](Self::with_modulus)).
+    pub fn random_with_modulus<R: Rng + ?Sized>(rng: &mut R, modulus: u64) -> Result<Self> {
+        Self::with_modulus(0, modulus).map(|hs| PolyHash {
+            base: rng.random_range(0..modulus),
+            ..hs
+        })
+    }
+
+    /// Podstawa funkcji haszującej.
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// Moduł funkcji haszującej.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// `a * b mod p`; dla 2^61 - 1 szybka redukcja z [`mul_mod`].
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        if self.modulus == MERSENNE_61 {
+            mul_mod(a, b)
+        } else {
+            (a as u128 * b as u128 % self.modulus as u128) as u64
+        }
+    }
+
+    /// `a + b mod p` dla `a, b < p`.
+    fn add(&self, a: u64, b: u64) -> u64 {
+        let r = a + b;
+        if r >= self.modulus {
+            r - self.modulus
+        } else {
+            r
+        }
+    }
+
+    /// `a - b mod p` dla `a, b < p`.
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        if a >= b { a - b } else { a + self.modulus - b }
+    }
+
+    /// Hash ciągu przedłużonego o symbol `symbol` na końcu.
+    pub fn push(&self, hash: u64, symbol: u64) -> u64 {
+        self.add(self.mul(hash, self.base), symbol % self.modulus)
+    }
+
+    /// Hash okna przesuniętego o jeden symbol: usuwamy `out` z początku okna,
+    /// dodajemy `inp` na końcu. `pow` to base^(m-1) dla okna długości m
+    /// (patrz [`pow`](Self::pow)).
+    pub fn roll(&self, hash: u64, out: u64, inp: u64, pow: u64) -> u64 {
+        let without_out = self.sub(hash, self.mul(out % self.modulus, pow));
+        self.push(without_out, inp)
+    }
+
+    /// base^e modulo p.
+    pub fn pow(&self, mut e: usize) -> u64 {
+        let mut result = 1;
+        let mut b = self.base;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = self.mul(result, b);
+            }
+            b = self.mul(b, b);
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Hash całego ciągu bajtów.
+    pub fn hash_bytes(&self, bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0, |acc, &b| self.push(acc, b as u64))
+    }
+}
+
+/// Indeks haszy prefiksów tekstu, budowany raz w O(n), odpowiadający na
+/// pytania o podsłowa w O(1): hash dowolnego fragmentu, równość dwóch
+/// fragmentów i (przez wyszukiwanie binarne, w O(log n)) najdłuższe wspólne
+/// przedłużenie (LCE) dwóch sufiksów.
+///
+/// Używa podwójnego modułu: dwóch niezależnych funkcji haszujących z losowymi
+/// podstawami, modulo 2^61 - 1 i modulo [`PRIME_62`] = 2^62 - 57. Dwa różne
+/// fragmenty długości m mają równe obie pary haszy z prawdopodobieństwem co
+/// najwyżej (m - 1)² / ((2^61 - 1)(2^62 - 57)). Odpowiedzi są więc poprawne
+/// z prawdopodobieństwem bliskim 1 (Monte Carlo) – wyniki krytyczne można
+/// zweryfikować bezpośrednim porównaniem.
+#[derive(Debug, Clone)]
+pub struct RollingHashIndex {
+    hashers: [PolyHash; 2],
+    prefix: [Vec<u64>; 2], // prefix[k][i] – hash text[0..i]
+    pow: [Vec<u64>; 2],    // pow[k][i] – base^i
+}
+
+impl RollingHashIndex {
+    /// Buduje indeks dla ciągu symboli (np. bajtów albo kodów znaków),
+    /// losując podstawy z `rng`.
+    pub fn new<T: Copy + Into<u64>, R: Rng + ?Sized>(text: &[T], rng: &mut R) -> Self {
+        Self::with_hashers(text, Self::random_hashers(rng))
+    }
+
+    /// Para funkcji haszujących, jakiej używa [`new`](Self::new): losowe
+    /// podstawy modulo 2^61 - 1 i modulo [`PRIME_62`].
+    pub fn random_hashers<R: Rng + ?Sized>(rng: &mut R) -> [PolyHash; 2] {
+        [
+            PolyHash::random(rng),
+            PolyHash::random_with_modulus(rng, PRIME_62).expect("PRIME_62 < 2^63"),
+        ]
+    }
+
+    /// Buduje indeks z podanymi funkcjami haszującymi. Indeksy kilku tekstów
+    /// zbudowane z tymi samymi funkcjami mają porównywalne hasze fragmentów.
+    pub fn with_hashers<T: Copy + Into<u64>>(text: &[T], hashers: [PolyHash; 2]) -> Self {
+        let prefix = hashers.map(|hs| {
+            let mut prefix = Vec::with_capacity(text.len() + 1);
+            prefix.push(0);
+            for (i, &c) in text.iter().enumerate() {
+                prefix.push(hs.push(prefix[i], c.into()));
+            }
+            prefix
+        });
+        let pow = hashers.map(|hs| {
+            let mut pow = Vec::with_capacity(text.len() + 1);
+            pow.push(1);
+            for i in 0..text.len() {
+                pow.push(hs.mul(pow[i], hs.base()));
+            }
+            pow
+        });
+        RollingHashIndex {
+            hashers,
+            prefix,
+            pow,
+        }
+    }
+
+    /// Jak [`new`](Self::new), ale z podstawami losowanymi z generatora
+    /// zainicjowanego ziarnem `seed`.
+    pub fn with_seed<T: Copy + Into<u64>>(text: &[T], seed: u64) -> Self {
+        Self::new(text, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Długość zaindeksowanego tekstu.
+    pub fn len(&self) -> usize {
+        self.prefix[0].len() - 1
+    }
+
+    /// Czy zaindeksowany tekst jest pusty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Obie funkcje haszujące indeksu.
+    pub fn hashers(&self) -> &[PolyHash; 2] {
+        &self.hashers
+    }
+
+    /// Para haszy fragmentu `text[range]`:
+    ///   h(i..j) = prefix[j] - prefix[i] · base^(j-i).
+    ///
+    /// Panikuje, jeśli zakres wykracza poza tekst.
+    pub fn hash(&self, range: Range<usize>) -> (u64, u64) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "zakres {:?} poza tekstem długości {}",
+            range,
+            self.len()
+        );
+        let h = |k: usize| {
+            let hs = &self.hashers[k];
+            let shifted = hs.mul(self.prefix[k][range.start], self.pow[k][range.len()]);
+            hs.sub(self.prefix[k][range.end], shifted)
+        };
+        (h(0), h(1))
+    }
+
+    /// Czy fragmenty `text[a]` i `text[b]` są równe (z dokładnością do kolizji
+    /// haszy, patrz opis typu).
+    ///
+    /// Panikuje, jeśli któryś z zakresów wykracza poza tekst.
+    pub fn eq(&self, a: Range<usize>, b: Range<usize>) -> bool {
+        a.len() == b.len() && self.hash(a) == self.hash(b)
+    }
+
+    /// Najdłuższe wspólne przedłużenie: największe l takie, że
+    /// `text[i..i + l] == text[j..j + l]`, wyznaczane wyszukiwaniem binarnym.
+    ///
+    /// Panikuje, jeśli `i` lub `j` jest większe niż długość tekstu.
+    pub fn lce(&self, i: usize, j: usize) -> usize {
+        let n = self.len();
+        assert!(
+            i <= n && j <= n,
+            "pozycje {} i {} poza tekstem długości {}",
+            i,
+            j,
+            n
+        );
+        // Niezmiennik: przedłużenie długości lo pasuje, długości hi + 1 już nie.
+        let (mut lo, mut hi) = (0, n - i.max(j));
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            if self.eq(i..i + mid, j..j + mid) {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo
+    }
+
+    /// Porównanie leksykograficzne fragmentów `text[a]` i `text[b]` w
+    /// O(log n), przez [`lce`](Self::lce) i porównanie pierwszego różnego
+    /// symbolu (przekazanego w `text`, tym samym co przy budowie indeksu).
+    ///
+    /// Panikuje, jeśli któryś z zakresów wykracza poza tekst.
+    pub fn compare<T: Ord>(&self, text: &[T], a: Range<usize>, b: Range<usize>) -> Ordering {
+        let l = self.lce(a.start, b.start).min(a.len()).min(b.len());
+        if l == a.len() || l == b.len() {
+            a.len().cmp(&b.len())
+        } else {
+            text[a.start + l].cmp(&text[b.start + l])
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::automaton::next_empty_match;
+use super::matcher::Matcher;
+
+/// Funkcja prefiksowa (tablica brzegów) ciągu `p`:
+/// `pi[j]` to długość najdłuższego właściwego prefiksu `p[0..=j]`, który jest
+/// jednocześnie jego sufiksem. To ta sama wartość co `longest_prefix_sufix`
+/// w [`build_prefix_automaton`](super::build_prefix_automaton), ale liczona
+/// bez tablicy przejść, w czasie i pamięci O(m) niezależnie od alfabetu.
+pub fn prefix_function<T: PartialEq>(p: &[T]) -> Vec<usize> {
+    let m = p.len();
+    let mut pi = vec![0; m];
+    let mut k = 0; // długość bieżącego brzegu
+    for j in 1..m {
+        // Cofamy się po kolejnych brzegach, aż któryś da się przedłużyć o p[j].
+        while k > 0 && p[k] != p[j] {
+            k = pi[k - 1];
+        }
+        if p[k] == p[j] {
+            k += 1;
+        }
+        pi[j] = k;
+    }
+    pi
+}
+
+/// Wyszukuje wszystkie (także nakładające się) wystąpienia `pattern` w `text`
+/// algorytmem Knutha–Morrisa–Pratta. Działa dla dowolnego alfabetu, np.
+/// znaków, bajtów czy nukleotydów. Zwraca indeksy początków wystąpień.
+///
+/// Pusty wzorzec występuje na każdej pozycji 0..=text.len().
+pub fn kmp_search<T: PartialEq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let pi = prefix_function(pattern);
+    let mut wynik = Vec::new();
+    let mut k = 0; // liczba dopasowanych znaków wzorca
+    for (i, c) in text.iter().enumerate() {
+        while k > 0 && pattern[k] != *c {
+            k = pi[k - 1];
+        }
+        if pattern[k] == *c {
+            k += 1;
+        }
+        if k == m {
+            wynik.push(i + 1 - m);
+            k = pi[m - 1];
+        }
+    }
+    wynik
+}
+
+/// Wzorzec przygotowany do wyszukiwania algorytmem KMP.
+///
+/// W przeciwieństwie do [`PrefixAutomaton`](super::PrefixAutomaton), który
+/// przechowuje przejście dla każdej pary (stan, znak alfabetu), KMP pamięta
+/// tylko funkcję prefiksową – O(m) niezależnie od rozmiaru alfabetu – kosztem
+/// cofania się po brzegach przy niedopasowaniu (zamortyzowane O(1) na znak).
+/// Przy dużych alfabetach (np. Unicode) jest więc oszczędniejszy, a przy
+/// małych automat wykonuje dokładnie jedno przejście na znak.
+///
+/// Wyszukiwanie odbywa się na bajtach UTF-8, więc pozycje są w bajtach.
+#[derive(Debug, Clone)]
+pub struct Kmp {
+    pattern: Vec<u8>,
+    pi: Vec<usize>,
+}
+
+impl Kmp {
+    /// Przygotowuje wzorzec `pattern` i jego funkcję prefiksową.
+    pub fn new(pattern: &str) -> Self {
+        let pattern = pattern.as_bytes().to_vec();
+        let pi = prefix_function(&pattern);
+        Kmp { pattern, pi }
+    }
+
+    /// Funkcja prefiksowa wzorca (liczona na bajtach UTF-8), patrz [`prefix_function`].
+    pub fn prefix_function(&self) -> &[usize] {
+        &self.pi
+    }
+
+    /// Iterator po pozycjach (w bajtach) początków rozłącznych wystąpień wzorca.
+    pub fn find_non_overlapping_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> KmpMatches<'a, 't> {
+        KmpMatches::new(self, haystack.as_ref(), false)
+    }
+}
+
+/// Wszystkie wystąpienia, łącznie z nakładającymi się.
+impl Matcher for Kmp {
+    type Iter<'a, 't> = KmpMatches<'a, 't>;
+
+    fn compile(pattern: &str) -> Self {
+        Kmp::new(pattern)
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(&'a self, haystack: &'t H) -> KmpMatches<'a, 't> {
+        KmpMatches::new(self, haystack.as_ref(), true)
+    }
+}
+
+/// Iterator po pozycjach wystąpień wzorca zwracany przez [`Matcher::find_iter`]
+/// i [`Kmp::find_non_overlapping_iter`].
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+#[derive(Debug, Clone)]
+pub struct KmpMatches<'a, 't> {
+    kmp: &'a Kmp,
+    haystack: &'t [u8],
+    pos: usize,
+    k: usize, // liczba dopasowanych bajtów wzorca
+    overlapping: bool,
+    done: bool,
+}
+
+impl<'a, 't> KmpMatches<'a, 't> {
+    fn new(kmp: &'a Kmp, haystack: &'t [u8], overlapping: bool) -> Self {
+        KmpMatches {
+            kmp,
+            haystack,
+            pos: 0,
+            k: 0,
+            overlapping,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for KmpMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let pattern = &self.kmp.pattern;
+        let m = pattern.len();
+        if m == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        while self.pos < self.haystack.len() {
+            let b = self.haystack[self.pos];
+            self.pos += 1;
+            while self.k > 0 && pattern[self.k] != b {
+                self.k = self.kmp.pi[self.k - 1];
+            }
+            if pattern[self.k] == b {
+                self.k += 1;
+            }
+            if self.k == m {
+                // Po dopasowaniu kontynuujemy od najdłuższego brzegu wzorca,
+                // a w trybie rozłącznym – od zera.
+                self.k = if self.overlapping {
+                    self.kmp.pi[m - 1]
+                } else {
+                    0
+                };
+                return Some(self.pos - m);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
@@ -0,0 +1,37 @@
+/// Wspólny interfejs algorytmów wyszukujących jeden wzorzec w tekście.
+///
+/// Wzorzec przygotowywany jest raz ([`compile`](Self::compile)), a potem można
+/// go szukać w wielu tekstach. Tekstem może być `&str` albo `&[u8]`; pozycje
+/// wystąpień liczone są w bajtach, więc dla `&str` zawsze wypadają na
+/// granicach znaków. Wszystkie implementacje zwracają te same wystąpienia,
+/// więc można je dowolnie zamieniać, np. w porównaniach wydajności.
+pub trait Matcher {
+    /// Iterator po pozycjach wystąpień zwracany przez [`find_iter`](Self::find_iter).
+    type Iter<'a, 't>: Iterator<Item = usize>
+    where
+        Self: 'a;
+
+    /// Przygotowuje wzorzec `pattern` do wyszukiwania.
+    fn compile(pattern: &str) -> Self
+    where
+        Self: Sized;
+
+    /// Iterator po pozycjach (w bajtach) początków wszystkich wystąpień wzorca
+    /// w `haystack`, łącznie z nakładającymi się, w kolejności rosnącej.
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(&'a self, haystack: &'t H) -> Self::Iter<'a, 't>;
+
+    /// Pozycja pierwszego wystąpienia wzorca albo `None`.
+    fn find_first<H: AsRef<[u8]> + ?Sized>(&self, haystack: &H) -> Option<usize> {
+        self.find_iter(haystack).next()
+    }
+
+    /// Liczba wszystkich wystąpień wzorca, łącznie z nakładającymi się.
+    fn count<H: AsRef<[u8]> + ?Sized>(&self, haystack: &H) -> usize {
+        self.find_iter(haystack).count()
+    }
+
+    /// Czy wzorzec występuje w `haystack`.
+    fn is_match<H: AsRef<[u8]> + ?Sized>(&self, haystack: &H) -> bool {
+        self.find_first(haystack).is_some()
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::regex::Dfa;
+
+/// Wspólny interfejs deterministycznych automatów skończonych, np.
+/// [`PrefixAutomaton`](super::PrefixAutomaton) i [`Dfa`]. Stan początkowy to 0.
+pub trait Automaton {
+    /// Liczba stanów.
+    fn state_count(&self) -> usize;
+
+    /// Czy stan `state` jest akceptujący.
+    fn is_accepting(&self, state: usize) -> bool;
+
+    /// Stan po wczytaniu `c` w stanie `state`; `None` oznacza odrzucenie
+    /// (przejście do niejawnego stanu martwego).
+    fn next_state(&self, state: usize, c: char) -> Option<usize>;
+
+    /// Posortowane początki przedziałów alfabetu (pierwszy to `'\0'`), wewnątrz
+    /// których automat nie rozróżnia znaków. Wystarczy więc sprawdzać przejścia
+    /// dla tych znaków.
+    fn alphabet(&self) -> Vec<char>;
+}
+
+/// Minimalizuje automat algorytmem Hopcrofta.
+///
+/// Najpierw odrzucane są stany nieosiągalne, a automat jest uzupełniany
+/// stanem martwym. Potem podział {akceptujące, pozostałe} jest rozbijany:
+/// dla bloku `A` z kolejki i symbolu `c` każdy blok dzielony jest na stany,
+/// które po `c` trafiają do `A`, i pozostałe; do kolejki wraca mniejsza część,
+/// dzięki czemu każdy stan trafia do kolejki O(log n) razy, a całość działa
+/// w czasie O(k · n log n) dla alfabetu o `k` przedziałach.
+///
+/// Wynikowy [`Dfa`] ma po jednym stanie na klasę równoważnych stanów
+/// (bez stanu martwego – przejścia do niego są pomijane), ponumerowane
+/// w kolejności przeszukiwania wszerz od stanu początkowego. Liczby stanów
+/// przed i po minimalizacji to [`Automaton::state_count`] obu automatów.
+pub fn minimize<A: Automaton + ?Sized>(automaton: &A) -> Dfa {
+    let alphabet = automaton.alphabet();
+    let k = alphabet.len();
+
+    // Stany osiągalne (w kolejności BFS) i stan martwy `dead` na końcu.
+    let mut index: HashMap<usize, usize> = HashMap::from([(0, 0)]);
+    let mut original = vec![0];
+    let mut i = 0;
+    while i < original.len() {
+        for &c in &alphabet {
+            if let Some(t) = automaton.next_state(original[i], c) {
+                index.entry(t).or_insert_with(|| {
+                    original.push(t);
+                    original.len() - 1
+                });
+            }
+        }
+        i += 1;
+    }
+    let dead = original.len();
+    let mut delta: Vec<Vec<usize>> = original
+        .iter()
+        .map(|&s| {
+            alphabet
+                .iter()
+                .map(|&c| automaton.next_state(s, c).map_or(dead, |t| index[&t]))
+                .collect()
+        })
+        .collect();
+    delta.push(vec![dead; k]);
+    let mut accepting: Vec<bool> = original
+        .iter()
+        .map(|&s| automaton.is_accepting(s))
+        .collect();
+    accepting.push(false);
+
+    let block_of = hopcroft(&delta, &accepting);
+
+    // Klasy numerujemy wszerz od klasy stanu początkowego, pomijając martwą.
+    let dead_block = block_of[dead];
+    let mut number: HashMap<usize, usize> = HashMap::new();
+    let mut representative = Vec::new();
+    let mut queue = VecDeque::new();
+    if block_of[0] != dead_block {
+        number.insert(block_of[0], 0);
+        representative.push(0);
+        queue.push_back(0);
+    }
+    let mut transitions = Vec::new();
+    while let Some(s) = queue.pop_front() {
+        let mut row = HashMap::new();
+        for (ci, &c) in alphabet.iter().enumerate() {
+            let t = delta[s][ci];
+            if block_of[t] == dead_block {
+                continue;
+            }
+            let id = *number.entry(block_of[t]).or_insert_with(|| {
+                representative.push(t);
+                queue.push_back(t);
+                representative.len() - 1
+            });
+            row.insert(c, id);
+        }
+        transitions.push(row);
+    }
+    if transitions.is_empty() {
+        // Pusty język: jeden stan odrzucający bez przejść.
+        return Dfa::from_parts(vec![HashMap::new()], vec![false], alphabet);
+    }
+    let accepting = representative.iter().map(|&s| accepting[s]).collect();
+    Dfa::from_parts(transitions, accepting, alphabet)
+}
+
+/// Algorytm Hopcrofta dla pełnego automatu `delta[stan][symbol]`;
+/// zwraca numer bloku (klasy równoważności) każdego stanu.
+fn hopcroft(delta: &[Vec<usize>], accepting: &[bool]) -> Vec<usize> {
+    let n = delta.len();
+    let k = delta.first().map_or(0, Vec::len);
+    // inverse[c][t] – stany przechodzące do `t` po symbolu `c`.
+    let mut inverse = vec![vec![Vec::new(); n]; k];
+    for (s, row) in delta.iter().enumerate() {
+        for (c, &t) in row.iter().enumerate() {
+            inverse[c][t].push(s);
+        }
+    }
+
+    let (acc, rej): (Vec<usize>, Vec<usize>) = (0..n).partition(|&s| accepting[s]);
+    let mut blocks: Vec<Vec<usize>> = [acc, rej].into_iter().filter(|b| !b.is_empty()).collect();
+    let mut block_of = vec![0; n];
+    for (b, states) in blocks.iter().enumerate() {
+        for &s in states {
+            block_of[s] = b;
+        }
+    }
+
+    let mut queued = vec![vec![false; k]; blocks.len()];
+    let mut queue = Vec::new();
+    if blocks.len() == 2 {
+        let smaller = usize::from(blocks[1].len() < blocks[0].len());
+        for (c, q) in queued[smaller].iter_mut().enumerate() {
+            *q = true;
+            queue.push((smaller, c));
+        }
+    }
+
+    let mut marked: Vec<Vec<usize>> = vec![Vec::new(); blocks.len()];
+    while let Some((a, c)) = queue.pop() {
+        queued[a][c] = false;
+        let mut touched = Vec::new();
+        for &t in &blocks[a] {
+            for &s in &inverse[c][t] {
+                let b = block_of[s];
+                if marked[b].is_empty() {
+                    touched.push(b);
+                }
+                marked[b].push(s);
+            }
+        }
+        for b in touched {
+            let split = std::mem::take(&mut marked[b]);
+            if split.len() == blocks[b].len() {
+                continue;
+            }
+            let new = blocks.len();
+            for &s in &split {
+                block_of[s] = new;
+            }
+            blocks[b].retain(|&s| block_of[s] == b);
+            blocks.push(split);
+            marked.push(Vec::new());
+            queued.push(vec![false; k]);
+            let was_queued = queued[b].clone();
+            for (c, was_queued) in was_queued.into_iter().enumerate() {
+                // Jeśli `b` czeka w kolejce, trzeba też sprawdzić nową część;
+                // w przeciwnym razie wystarczy mniejsza z dwóch.
+                let target = if was_queued || blocks[new].len() <= blocks[b].len() {
+                    new
+                } else {
+                    b
+                };
+                queued[target][c] = true;
+                queue.push((target, c));
+            }
+        }
+    }
+    block_of
+}
+
+/// Najkrótsze słowo akceptowane przez dokładnie jeden z automatów albo
+/// `None`, jeśli rozpoznają ten sam język.
+///
+/// Przeszukiwanie wszerz automatu produktowego (pary stanów, z jawnym stanem
+/// martwym) po wspólnym podziale alfabetów obu automatów; czas
+/// O(n_a · n_b · k).
+pub fn counterexample<A, B>(a: &A, b: &B) -> Option<String>
+where
+    A: Automaton + ?Sized,
+    B: Automaton + ?Sized,
+{
+    let mut alphabet = a.alphabet();
+    alphabet.extend(b.alphabet());
+    alphabet.sort_unstable();
+    alphabet.dedup();
+
+    type Pair = (Option<usize>, Option<usize>);
+    let accepts = |(x, y): Pair| {
+        (
+            x.is_some_and(|x| a.is_accepting(x)),
+            y.is_some_and(|y| b.is_accepting(y)),
+        )
+    };
+    let start: Pair = (Some(0), Some(0));
+    // Poprzednik pary i znak, którym do niej przeszliśmy.
+    let mut parent: HashMap<Pair, Option<(Pair, char)>> = HashMap::from([(start, None)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(pair) = queue.pop_front() {
+        let (acc_a, acc_b) = accepts(pair);
+        if acc_a != acc_b {
+            let mut word = Vec::new();
+            let mut cur = pair;
+            while let Some((prev, c)) = parent[&cur] {
+                word.push(c);
+                cur = prev;
+            }
+            return Some(word.into_iter().rev().collect());
+        }
+        for &c in &alphabet {
+            let next = (
+                pair.0.and_then(|x| a.next_state(x, c)),
+                pair.1.and_then(|y| b.next_state(y, c)),
+            );
+            // Para martwych stanów nie odróżni już automatów.
+            if next == (None, None) || parent.contains_key(&next) {
+                continue;
+            }
+            parent.insert(next, Some((pair, c)));
+            queue.push_back(next);
+        }
+    }
+    None
+}
+
+/// Czy oba automaty rozpoznają ten sam język (patrz [`counterexample`]).
+pub fn equivalent<A, B>(a: &A, b: &B) -> bool
+where
+    A: Automaton + ?Sized,
+    B: Automaton + ?Sized,
+{
+    counterexample(a, b).is_none()
+}
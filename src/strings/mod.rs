@@ -0,0 +1,88 @@
+//! Algorytmy tekstowe: wyszukiwanie wzorców (dokładne i przybliżone), indeksy
+//! pełnotekstowe i analiza struktury słów.
+//!
+//! Algorytmy dla jednego wzorca implementują wspólny trait [`Matcher`].
+//!
+//! Pusty wzorzec nie jest błędem: występuje na każdej granicy znaku tekstu,
+//! łącznie z jego końcem, a w funkcjach dla ciągów dowolnych symboli (np.
+//! [`kmp_search`], [`z_search`]) – na każdej pozycji `0..=n`.
+//!
+//! - [`approximate`] – wyszukiwanie przybliżone z k niezgodnościami lub k edycjami,
+//! - [`assembly`] – macierz zakładek i zachłanne najkrótsze wspólne nadsłowo,
+//! - [`automaton`] – prefiksowy automat skończony (DFA) dla jednego wzorca,
+//! - [`aho_corasick`] – automat Aho–Corasick dla wielu wzorców,
+//! - [`boyer_moore`] – algorytmy Boyera–Moore'a i Horspoola, czytające tylko część tekstu,
+//! - [`dot`] – eksport automatów do formatu DOT (Graphviz) i tekstowe tablice przejść,
+//! - [`fm_index`] – transformata Burrowsa–Wheelera i FM-indeks z próbkowaniem,
+//! - [`hashing`] – haszowanie wielomianowe z losową podstawą (modulo 2^61 - 1)
+//!   i indeks haszy prefiksów z podwójnym modułem do porównywania podsłów w O(1),
+//! - [`kmp`] – algorytm Knutha–Morrisa–Pratta oparty na funkcji prefiksowej,
+//! - [`minimize`] – minimalizacja DFA algorytmem Hopcrofta i sprawdzanie równoważności,
+//! - [`naive`] – wyszukiwanie naiwne, punkt odniesienia dla pozostałych,
+//! - [`periodicity`] – prefiksy, sufiksy, zakładki, brzegi i okresy słów,
+//! - [`rabin_karp`] – algorytm Rabina–Karpa z haszowaniem kroczącym,
+//! - [`rabin_karp_2d`] – Rabin–Karp dla bloków w dwuwymiarowych siatkach,
+//! - [`regex`] – wyrażenia regularne: parser, NFA Thompsona i DFA z konstrukcji podzbiorów,
+//! - [`repeats`] – najdłuższe powtórzone i najdłuższe wspólne podsłowo (haszowanie),
+//! - [`shift_and`] – bitowo-równoległy Shift-And z klasami znaków i pozycjami dowolnymi,
+//! - [`stream`] – wyszukiwanie w strumieniach (`io::Read`) czytanych porcjami,
+//! - [`suffix_array`] – tablica sufiksowa, tablica LCP (Kasai) i wyszukiwanie binarne,
+//! - [`suffix_automaton`] – automat sufiksowy (DAWG): podsłowa, ich liczba i wystąpienia,
+//! - [`z_algorithm`] – tablica Z i wyszukiwanie oparte na niej.
+
+pub mod aho_corasick;
+pub mod approximate;
+pub mod assembly;
+pub mod automaton;
+pub mod boyer_moore;
+pub mod dot;
+pub mod fm_index;
+pub mod hashing;
+pub mod kmp;
+pub mod matcher;
+pub mod minimize;
+pub mod naive;
+pub mod periodicity;
+pub mod rabin_karp;
+pub mod rabin_karp_2d;
+pub mod regex;
+pub mod repeats;
+pub mod shift_and;
+pub mod stream;
+pub mod suffix_array;
+pub mod suffix_automaton;
+pub mod z_algorithm;
+
+pub use aho_corasick::{AhoCorasick, Match, MatchKind};
+pub use approximate::{
+    ApproxMatch, edit_search, edit_search_str, hamming_search, hamming_search_str,
+};
+pub use assembly::{Superstring, overlap_matrix, shortest_common_superstring};
+pub use automaton::{
+    Matches, PrefixAutomaton, Representation, build_prefix_automaton, find_pattern, pattern_exists,
+};
+pub use boyer_moore::{BoyerMoore, BoyerMooreMatches, Horspool, HorspoolMatches};
+pub use dot::{DotOptions, to_dot, transition_table};
+pub use fm_index::{FmIndex, bwt, inverse_bwt};
+pub use hashing::{PolyHash, RollingHashIndex};
+pub use kmp::{Kmp, KmpMatches, kmp_search, prefix_function};
+pub use matcher::Matcher;
+pub use minimize::{Automaton, counterexample, equivalent, minimize};
+pub use naive::{Naive, NaiveMatches, naive_match};
+pub use periodicity::{
+    borders, is_prefix, is_primitive, is_suffix, overlap, primitive_root, shortest_period,
+};
+pub use rabin_karp::{
+    MultiRabinKarp, RabinKarp, RabinKarpMatches, RabinKarpOptions, RandomizedRabinKarp,
+    RandomizedRabinKarpMatches, rabin_karp, rabin_karp_chars,
+};
+pub use rabin_karp_2d::{Grid, rabin_karp_2d};
+pub use regex::{Dfa, Nfa};
+pub use repeats::{
+    CommonSubstring, RepeatedSubstring, longest_common_substring, longest_repeated_substring,
+};
+pub use shift_and::{PatternSymbol, ShiftAnd, ShiftAndMatches};
+pub use stream::{StreamAlgorithm, StreamMatches, stream_find_iter};
+pub use suffix_array::{SuffixArray, lcp_array, suffix_array};
+pub use suffix_automaton::SuffixAutomaton;
+pub use z_algorithm::{z_array, z_search};
@@ -0,0 +1,91 @@
+use super::automaton::next_empty_match;
+use super::matcher::Matcher;
+
+/// Naiwne wyszukiwanie wzorca w tekście: porównuje wzorzec z każdym
+/// fragmentem tekstu o długości m (odpowiednik `memcmp`), w czasie O(nm).
+/// Zwraca indeks (w bajtach) pierwszego wystąpienia wzorca albo `None`.
+pub fn naive_match(text: &str, pattern: &str) -> Option<usize> {
+    let text = text.as_bytes();
+    let pattern = pattern.as_bytes();
+    let n = text.len();
+    let m = pattern.len();
+
+    // Jeśli wzorzec jest dłuższy od tekstu, nie ma sensu szukać
+    if m > n {
+        return None;
+    }
+
+    // Iterujemy po wszystkich możliwych pozycjach, gdzie wzorzec mógłby się zmieścić
+    (0..=n - m).find(|&i| &text[i..i + m] == pattern)
+}
+
+/// Wzorzec do wyszukiwania naiwnego (patrz [`naive_match`]).
+#[derive(Debug, Clone)]
+pub struct Naive {
+    pattern: Vec<u8>,
+}
+
+impl Naive {
+    /// Zapamiętuje wzorzec `pattern`; algorytm nie wymaga przetwarzania wstępnego.
+    pub fn new(pattern: &str) -> Self {
+        Naive {
+            pattern: pattern.as_bytes().to_vec(),
+        }
+    }
+}
+
+/// Wszystkie wystąpienia, łącznie z nakładającymi się.
+impl Matcher for Naive {
+    type Iter<'a, 't> = NaiveMatches<'a, 't>;
+
+    fn compile(pattern: &str) -> Self {
+        Naive::new(pattern)
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> NaiveMatches<'a, 't> {
+        NaiveMatches {
+            naive: self,
+            haystack: haystack.as_ref(),
+            pos: 0,
+            done: false,
+        }
+    }
+}
+
+/// Iterator po pozycjach wystąpień zwracany przez [`Naive`].
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+#[derive(Debug, Clone)]
+pub struct NaiveMatches<'a, 't> {
+    naive: &'a Naive,
+    haystack: &'t [u8],
+    pos: usize,
+    done: bool,
+}
+
+impl Iterator for NaiveMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let p = &self.naive.pattern;
+        let m = p.len();
+        if m == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        while self.pos + m <= self.haystack.len() {
+            let i = self.pos;
+            self.pos += 1;
+            if &self.haystack[i..i + m] == p {
+                return Some(i);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
@@ -0,0 +1,74 @@
+use super::kmp::prefix_function;
+
+/// Sprawdza, czy `prefix` jest prefiksem `text` (odpowiednik `is_prefix` z 2.c).
+pub fn is_prefix<T: PartialEq>(prefix: &[T], text: &[T]) -> bool {
+    text.starts_with(prefix)
+}
+
+/// Sprawdza, czy `suffix` jest sufiksem (postfiksem) `text`
+/// (odpowiednik `is_suffix` z 2.c).
+pub fn is_suffix<T: PartialEq>(suffix: &[T], text: &[T]) -> bool {
+    text.ends_with(suffix)
+}
+
+/// Największe `k`, dla którego `x[..k] == y[y.len() - k..]`, czyli długość
+/// najdłuższego prefiksu `x` będącego sufiksem `y` (odpowiednik `overlap`
+/// z 3.c i 3.py).
+///
+/// Zamiast sprawdzać każde `k` osobno (O(min(n, m)²) jak w oryginale) liczy
+/// funkcję prefiksową ciągu `x + sep + y`, gdzie separator `sep` jest różny od
+/// wszystkich symboli (tu: `None` wśród `Some(&symbol)`). Ostatnia wartość to
+/// najdłuższy brzeg całości, a separator gwarantuje, że nie przekracza on
+/// ani `x`, ani `y` – czas O(n + m).
+pub fn overlap<T: PartialEq>(x: &[T], y: &[T]) -> usize {
+    let joined: Vec<Option<&T>> = x
+        .iter()
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain(y.iter().map(Some))
+        .collect();
+    prefix_function(&joined).last().copied().unwrap_or(0)
+}
+
+/// Długości wszystkich niepustych właściwych brzegów `s` (słów będących
+/// jednocześnie prefiksem i sufiksem `s`) w kolejności malejącej.
+///
+/// Kolejne brzegi to łańcuch `pi[n-1], pi[pi[n-1]-1], …` funkcji prefiksowej,
+/// więc całość działa w czasie O(n).
+pub fn borders<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let pi = prefix_function(s);
+    let mut wynik = Vec::new();
+    let mut k = pi.last().copied().unwrap_or(0);
+    while k > 0 {
+        wynik.push(k);
+        k = pi[k - 1];
+    }
+    wynik
+}
+
+/// Najkrótszy okres `s`: najmniejsze `p > 0`, dla którego `s[i] == s[i + p]`
+/// dla wszystkich `i`. Równy `n` minus długość najdłuższego brzegu, O(n).
+/// Dla pustego ciągu zwraca 0.
+pub fn shortest_period<T: PartialEq>(s: &[T]) -> usize {
+    s.len() - prefix_function(s).last().copied().unwrap_or(0)
+}
+
+/// Sprawdza, czy `s` jest pierwotne, tzn. nie jest postaci `u^k` dla `k ≥ 2`.
+/// Słowo jest potęgą wtedy i tylko wtedy, gdy jego najkrótszy okres jest
+/// właściwym dzielnikiem długości. Pusty ciąg nie jest pierwotny.
+pub fn is_primitive<T: PartialEq>(s: &[T]) -> bool {
+    let n = s.len();
+    let p = shortest_period(s);
+    n > 0 && (p == n || !n.is_multiple_of(p))
+}
+
+/// Pierwiastek pierwotny `s`: najkrótsze `u`, dla którego `s == u^k`.
+/// Dla słów pierwotnych (i pustego ciągu) jest to całe `s`.
+pub fn primitive_root<T: PartialEq>(s: &[T]) -> &[T] {
+    let p = shortest_period(s);
+    if p > 0 && s.len().is_multiple_of(p) {
+        &s[..p]
+    } else {
+        s
+    }
+}
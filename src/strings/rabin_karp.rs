@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::automaton::{char_boundaries, next_empty_match};
+use super::hashing::PolyHash;
+use super::matcher::Matcher;
+
+/// Funkcja szukająca wzorca `pattern` w tekście `text` przy użyciu algorytmu Rabina-Karpa.
+/// Haszuje i porównuje bajty UTF-8, więc działa dla dowolnego tekstu (także z
+/// polskimi znakami), a wystąpienia zawsze wypadają na granicach znaków.
+/// Zwraca wektor indeksów (w bajtach), pod którymi wzorzec występuje w tekście.
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+pub fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        return char_boundaries(text.as_bytes()).collect();
+    }
+    rabin_karp_symbols(text.as_bytes(), pattern.as_bytes())
+}
+
+/// Jak [`rabin_karp`], ale haszuje całe znaki (kody Unicode) zamiast bajtów
+/// i zwraca indeksy wystąpień liczone w znakach, np. "kot" w "ąkot" to 1.
+///
+/// Pusty wzorzec występuje na każdej pozycji 0..=liczba znaków tekstu.
+pub fn rabin_karp_chars(text: &str, pattern: &str) -> Vec<usize> {
+    let text: Vec<u32> = text.chars().map(u32::from).collect();
+    if pattern.is_empty() {
+        return (0..=text.len()).collect();
+    }
+    let pattern: Vec<u32> = pattern.chars().map(u32::from).collect();
+    rabin_karp_symbols(&text, &pattern)
+}
+
+/// Rabin–Karp dla ciągów dowolnych symboli zamienialnych na liczby; wspólna
+/// część [`rabin_karp`] i [`rabin_karp_chars`]. Wymaga niepustego wzorca.
+fn rabin_karp_symbols<T: Copy + Into<u64> + PartialEq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    let n = text.len();
+    let m = pattern.len();
+    let mut wynik = Vec::new();
+
+    // Jeśli długość wzorca jest większa niż tekst, zwracamy pusty wynik.
+    if m > n {
+        return wynik;
+    }
+
+    // Parametry haszujące:
+    // d – liczba możliwych znaków (np. 256 dla rozszerzonego ASCII)
+    // q – liczba pierwsza używana do redukcji kolizji (modulo)
+    let d: u64 = 256;
+    let q: u64 = 101;
+
+    // Inicjalizacja wartości haszujących:
+    let mut hash_pattern = 0u64;
+    let mut hash_text = 0u64;
+    let mut h = 1u64; // h = d^(m-1) mod q
+
+    // Obliczenie wartości h
+    for _ in 0..(m - 1) {
+        h = (h * d) % q;
+    }
+
+    // Obliczenie początkowych wartości hash dla wzorca i pierwszego okna tekstu
+    for i in 0..m {
+        hash_pattern = (d * hash_pattern + pattern[i].into()) % q;
+        hash_text = (d * hash_text + text[i].into()) % q;
+    }
+
+    // Przesuwamy okno przez tekst
+    for i in 0..=(n - m) {
+        // Jeśli wartości hash się zgadzają, dokonujemy dokładnego porównania symboli
+        if hash_pattern == hash_text && text[i..i + m] == *pattern {
+            wynik.push(i);
+        }
+
+        // Jeśli nie jesteśmy na końcu tekstu, aktualizujemy hash dla kolejnego okna
+        if i < n - m {
+            // Obliczamy hash dla nowego okna:
+            // Odejmujemy wkład pierwszego symbolu poprzedniego okna,
+            // przesuwamy okno (mnożymy przez d) i dodajemy nowy symbol.
+            let tmp = (hash_text + q - (text[i].into() % q * h) % q) % q;
+            hash_text = (d * tmp + text[i + m].into()) % q;
+        }
+    }
+
+    wynik
+}
+
+// Parametry haszujące używane przez [`RabinKarp`] – te same co w [`rabin_karp`].
+const D: u64 = 256;
+const Q: u64 = 101;
+
+/// Wzorzec przygotowany do wyszukiwania algorytmem Rabina–Karpa: zapamiętuje
+/// hash wzorca i wartość `h = d^(m-1) mod q` potrzebną do przesuwania okna.
+#[derive(Debug, Clone)]
+pub struct RabinKarp {
+    pattern: Vec<u8>,
+    hash_pattern: u64,
+    h: u64,
+}
+
+impl RabinKarp {
+    /// Przygotowuje wzorzec `pattern`.
+    pub fn new(pattern: &str) -> Self {
+        Self::from_bytes(pattern.as_bytes())
+    }
+
+    pub(crate) fn from_bytes(pattern: &[u8]) -> Self {
+        let pattern = pattern.to_vec();
+        let mut h = 1u64;
+        for _ in 1..pattern.len() {
+            h = (h * D) % Q;
+        }
+        let hash_pattern = pattern.iter().fold(0, |acc, &b| Self::push(acc, b));
+        RabinKarp {
+            pattern,
+            hash_pattern,
+            h,
+        }
+    }
+
+    pub(crate) fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    pub(crate) fn hash_pattern(&self) -> u64 {
+        self.hash_pattern
+    }
+
+    /// Hash okna przedłużonego o bajt `b` na końcu.
+    pub(crate) fn push(hash: u64, b: u8) -> u64 {
+        (D * hash + b as u64) % Q
+    }
+
+    /// Hash okna przesuniętego o jeden bajt: odejmujemy wkład bajtu `out`
+    /// z początku okna, przesuwamy okno (mnożymy przez d) i dodajemy `inp`.
+    pub(crate) fn roll(&self, hash: u64, out: u8, inp: u8) -> u64 {
+        let tmp = (hash + Q - (out as u64 * self.h) % Q) % Q;
+        Self::push(tmp, inp)
+    }
+}
+
+/// Wszystkie wystąpienia, łącznie z nakładającymi się.
+impl Matcher for RabinKarp {
+    type Iter<'a, 't> = RabinKarpMatches<'a, 't>;
+
+    fn compile(pattern: &str) -> Self {
+        RabinKarp::new(pattern)
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> RabinKarpMatches<'a, 't> {
+        let haystack = haystack.as_ref();
+        let m = self.pattern.len();
+        // Hash pierwszego okna tekstu (o ile tekst mieści wzorzec).
+        let hash_text = haystack
+            .get(..m)
+            .map_or(0, |w| w.iter().fold(0, |acc, &b| RabinKarp::push(acc, b)));
+        RabinKarpMatches {
+            rk: self,
+            haystack,
+            pos: 0,
+            hash_text,
+            done: false,
+        }
+    }
+}
+
+/// Iterator po pozycjach wystąpień zwracany przez [`RabinKarp`].
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+#[derive(Debug, Clone)]
+pub struct RabinKarpMatches<'a, 't> {
+    rk: &'a RabinKarp,
+    haystack: &'t [u8],
+    pos: usize,     // początek bieżącego okna
+    hash_text: u64, // hash okna haystack[pos..pos + m]
+    done: bool,
+}
+
+impl Iterator for RabinKarpMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let p = &self.rk.pattern;
+        let m = p.len();
+        if m == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        let t = self.haystack;
+        let n = t.len();
+        while self.pos + m <= n {
+            let i = self.pos;
+            // Jeśli wartości hash się zgadzają, dokonujemy dokładnego porównania bajtów
+            let hit = self.hash_text == self.rk.hash_pattern && &t[i..i + m] == p;
+            // Przesuwamy okno: usuwamy wkład t[i] i dodajemy t[i + m].
+            if i + m < n {
+                self.hash_text = self.rk.roll(self.hash_text, t[i], t[i + m]);
+            }
+            self.pos += 1;
+            if hit {
+                return Some(i);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
+
+/// Opcje [`RandomizedRabinKarp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RabinKarpOptions {
+    /// Dwa niezależne hasze (dwie losowe podstawy) zamiast jednego; okno jest
+    /// kandydatem tylko, gdy oba się zgadzają.
+    pub double_hashing: bool,
+    /// Wariant Monte Carlo: zgodność haszy uznawana jest za wystąpienie bez
+    /// porównywania bajtów (patrz [`RandomizedRabinKarp`]).
+    pub monte_carlo: bool,
+}
+
+/// Rabin–Karp z haszowaniem modulo 2^61 - 1 i losową podstawą
+/// (patrz [`PolyHash`]).
+///
+/// Przy stałych `d = 256`, `q = 101` z [`rabin_karp`] prawie każde okno koliduje
+/// z wzorcem, a przeciwnik znający parametry może wymusić Θ(nm) porównań.
+/// Tu podstawa jest losowana przy budowie, więc dla tekstu niezależnego od
+/// losowania pojedyncze okno różne od wzorca koliduje z prawdopodobieństwem
+/// co najwyżej (m - 1) / (2^61 - 1), a oczekiwany czas wyszukiwania to O(n + m).
+///
+/// W wariancie Monte Carlo ([`RabinKarpOptions::monte_carlo`]) trafienia nie są
+/// weryfikowane. Prawdopodobieństwo zgłoszenia choć jednej fałszywej pozycji
+/// w tekście długości n jest wtedy ograniczone przez
+///   (n - m + 1) · (m - 1) / (2^61 - 1)
+/// dla jednego haszu i przez (n - m + 1) · ((m - 1) / (2^61 - 1))² dla
+/// podwójnego – np. dla n = 10^9, m = 1000 to około 4,3 · 10^-7 i 1,9 · 10^-22.
+#[derive(Debug, Clone)]
+pub struct RandomizedRabinKarp {
+    pattern: Vec<u8>,
+    hashers: Vec<PolyHash>,
+    hash_pattern: Vec<u64>,
+    pow: Vec<u64>, // base^(m-1) dla każdej funkcji haszującej
+    verify: bool,
+}
+
+impl RandomizedRabinKarp {
+    /// Przygotowuje wzorzec `pattern`, losując podstawy z `rng`.
+    pub fn new<R: Rng + ?Sized>(pattern: &str, options: RabinKarpOptions, rng: &mut R) -> Self {
+        let pattern = pattern.as_bytes().to_vec();
+        let k = if options.double_hashing { 2 } else { 1 };
+        let hashers: Vec<PolyHash> = (0..k).map(|_| PolyHash::random(rng)).collect();
+        let hash_pattern = hashers.iter().map(|hs| hs.hash_bytes(&pattern)).collect();
+        let pow = hashers
+            .iter()
+            .map(|hs| hs.pow(pattern.len().saturating_sub(1)))
+            .collect();
+        RandomizedRabinKarp {
+            pattern,
+            hashers,
+            hash_pattern,
+            pow,
+            verify: !options.monte_carlo,
+        }
+    }
+
+    /// Jak [`new`](Self::new), ale podstawy losowane są z generatora
+    /// zainicjowanego ziarnem `seed`, więc wyniki są powtarzalne.
+    pub fn with_seed(pattern: &str, options: RabinKarpOptions, seed: u64) -> Self {
+        Self::new(pattern, options, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Wylosowane funkcje haszujące (jedna lub dwie).
+    pub fn hashers(&self) -> &[PolyHash] {
+        &self.hashers
+    }
+}
+
+/// Wszystkie wystąpienia, łącznie z nakładającymi się. [`Matcher::compile`]
+/// losuje podstawę z `rand::rng()` i weryfikuje trafienia.
+impl Matcher for RandomizedRabinKarp {
+    type Iter<'a, 't> = RandomizedRabinKarpMatches<'a, 't>;
+
+    fn compile(pattern: &str) -> Self {
+        RandomizedRabinKarp::new(pattern, RabinKarpOptions::default(), &mut rand::rng())
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> RandomizedRabinKarpMatches<'a, 't> {
+        let haystack = haystack.as_ref();
+        let m = self.pattern.len();
+        // Hashe pierwszego okna tekstu (o ile tekst mieści wzorzec).
+        let hash_text = match haystack.get(..m) {
+            Some(w) => self.hashers.iter().map(|hs| hs.hash_bytes(w)).collect(),
+            None => vec![0; self.hashers.len()],
+        };
+        RandomizedRabinKarpMatches {
+            rk: self,
+            haystack,
+            pos: 0,
+            hash_text,
+            done: false,
+        }
+    }
+}
+
+/// Iterator po pozycjach wystąpień zwracany przez [`RandomizedRabinKarp`].
+///
+/// Pusty wzorzec występuje na każdej granicy znaku, łącznie z końcem tekstu.
+#[derive(Debug, Clone)]
+pub struct RandomizedRabinKarpMatches<'a, 't> {
+    rk: &'a RandomizedRabinKarp,
+    haystack: &'t [u8],
+    pos: usize,          // początek bieżącego okna
+    hash_text: Vec<u64>, // hashe okna haystack[pos..pos + m]
+    done: bool,
+}
+
+impl Iterator for RandomizedRabinKarpMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let rk = self.rk;
+        let p = &rk.pattern;
+        let m = p.len();
+        if m == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        let t = self.haystack;
+        let n = t.len();
+        while self.pos + m <= n {
+            let i = self.pos;
+            let hit = self.hash_text == rk.hash_pattern && (!rk.verify || &t[i..i + m] == p);
+            if i + m < n {
+                for (k, hs) in rk.hashers.iter().enumerate() {
+                    self.hash_text[k] =
+                        hs.roll(self.hash_text[k], t[i] as u64, t[i + m] as u64, rk.pow[k]);
+                }
+            }
+            self.pos += 1;
+            if hit {
+                return Some(i);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
+
+/// Wzorce jednej długości w [`MultiRabinKarp`]: hash -> numery wzorców.
+#[derive(Debug, Clone)]
+struct LengthGroup {
+    len: usize,
+    pow: u64, // base^(len-1)
+    by_hash: HashMap<u64, Vec<usize>>,
+}
+
+/// Rabin–Karp dla wielu wzorców naraz, np. tysięcy odcisków (fingerprintów)
+/// porównywanych z dokumentem.
+///
+/// Hashe wzorców (losowa podstawa modulo 2^61 - 1, patrz [`PolyHash`])
+/// trzymane są w tablicy haszującej, osobno dla każdej długości wzorca. Tekst
+/// przechodzony jest oknem kroczącym raz dla każdej różnej długości, więc dla
+/// zbioru wzorców równej długości czas to oczekiwane O(n + suma długości
+/// wzorców + liczba wystąpień) niezależnie od liczby wzorców.
+#[derive(Debug, Clone)]
+pub struct MultiRabinKarp {
+    hasher: PolyHash,
+    patterns: Vec<Vec<u8>>,
+    groups: Vec<LengthGroup>, // posortowane rosnąco po długości
+}
+
+impl MultiRabinKarp {
+    /// Przygotowuje zbiór wzorców, losując podstawę haszowania z `rng`.
+    /// Numer wzorca w wynikach to jego indeks w `patterns`; pusty wzorzec
+    /// występuje na każdej granicy znaku, łącznie z końcem tekstu.
+    pub fn new<P: AsRef<str>, R: Rng + ?Sized>(patterns: &[P], rng: &mut R) -> Self {
+        let hasher = PolyHash::random(rng);
+        let patterns: Vec<Vec<u8>> = patterns
+            .iter()
+            .map(|p| p.as_ref().as_bytes().to_vec())
+            .collect();
+
+        let mut by_len: HashMap<usize, LengthGroup> = HashMap::new();
+        for (id, p) in patterns.iter().enumerate() {
+            let group = by_len.entry(p.len()).or_insert_with(|| LengthGroup {
+                len: p.len(),
+                pow: hasher.pow(p.len().saturating_sub(1)),
+                by_hash: HashMap::new(),
+            });
+            group
+                .by_hash
+                .entry(hasher.hash_bytes(p))
+                .or_default()
+                .push(id);
+        }
+        let mut groups: Vec<LengthGroup> = by_len.into_values().collect();
+        groups.sort_unstable_by_key(|g| g.len);
+
+        MultiRabinKarp {
+            hasher,
+            patterns,
+            groups,
+        }
+    }
+
+    /// Jak [`new`](Self::new), ale z podstawą losowaną z generatora
+    /// zainicjowanego ziarnem `seed`.
+    pub fn with_seed<P: AsRef<str>>(patterns: &[P], seed: u64) -> Self {
+        Self::new(patterns, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Liczba wzorców.
+    pub fn pattern_count(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Wszystkie zweryfikowane wystąpienia jako pary (numer wzorca, pozycja
+    /// w bajtach), posortowane po pozycji, a przy równej pozycji po numerze.
+    pub fn find_all<H: AsRef<[u8]> + ?Sized>(&self, haystack: &H) -> Vec<(usize, usize)> {
+        let t = haystack.as_ref();
+        let n = t.len();
+        let mut wynik = Vec::new();
+
+        for group in &self.groups {
+            let m = group.len;
+            if m > n {
+                break;
+            }
+            if m == 0 {
+                // Puste wzorce mają wspólny hash, więc tworzą jeden kubełek.
+                for i in char_boundaries(t) {
+                    wynik.extend(group.by_hash.values().flatten().map(|&id| (id, i)));
+                }
+                continue;
+            }
+            let mut hash_text = self.hasher.hash_bytes(&t[..m]);
+            for i in 0..=(n - m) {
+                // Zgodność haszy to tylko kandydat – porównujemy bajty.
+                if let Some(ids) = group.by_hash.get(&hash_text) {
+                    for &id in ids {
+                        if self.patterns[id] == t[i..i + m] {
+                            wynik.push((id, i));
+                        }
+                    }
+                }
+                if i < n - m {
+                    hash_text =
+                        self.hasher
+                            .roll(hash_text, t[i] as u64, t[i + m] as u64, group.pow);
+                }
+            }
+        }
+
+        wynik.sort_unstable_by_key(|&(id, pos)| (pos, id));
+        wynik
+    }
+}
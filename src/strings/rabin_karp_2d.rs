@@ -0,0 +1,151 @@
+use rand::Rng;
+
+use super::hashing::PolyHash;
+use crate::{Error, Result};
+
+/// Prostokątna siatka bajtów (np. kafelek obrazu lub plansza gry) zapisana
+/// wierszami w jednym wektorze.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    data: Vec<u8>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Grid {
+    /// Siatka o `cols` kolumnach z danych zapisanych wierszami.
+    ///
+    /// Zwraca [`Error::InvalidParameter`], jeśli długość danych nie jest
+    /// wielokrotnością `cols` (albo `cols` = 0 przy niepustych danych).
+    pub fn new(data: Vec<u8>, cols: usize) -> Result<Self> {
+        if data.is_empty() {
+            return Ok(Grid {
+                data,
+                rows: 0,
+                cols,
+            });
+        }
+        if cols == 0 || !data.len().is_multiple_of(cols) {
+            return Err(Error::InvalidParameter(
+                "długość danych musi być wielokrotnością cols",
+            ));
+        }
+        Ok(Grid {
+            rows: data.len() / cols,
+            data,
+            cols,
+        })
+    }
+
+    /// Siatka z listy wierszy, np. `&[Vec<u8>]` albo `&["abc", "def"]`.
+    ///
+    /// Zwraca [`Error::InvalidParameter`], jeśli wiersze mają różne długości.
+    pub fn from_rows<T: AsRef<[u8]>>(rows: &[T]) -> Result<Self> {
+        let cols = rows.first().map_or(0, |r| r.as_ref().len());
+        if rows.iter().any(|r| r.as_ref().len() != cols) {
+            return Err(Error::InvalidParameter(
+                "wszystkie wiersze muszą mieć równą długość",
+            ));
+        }
+        Ok(Grid {
+            data: rows
+                .iter()
+                .flat_map(|r| r.as_ref().iter().copied())
+                .collect(),
+            rows: rows.len(),
+            cols,
+        })
+    }
+
+    /// Liczba wierszy.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Liczba kolumn.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Wiersz numer `i`.
+    ///
+    /// Panikuje, jeśli `i >= rows()`.
+    pub fn row(&self, i: usize) -> &[u8] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+}
+
+/// Dwuwymiarowy Rabin–Karp: znajduje wszystkie wystąpienia bloku `pattern`
+/// (r×c) w siatce `text` (R×C) i zwraca współrzędne (wiersz, kolumna) ich
+/// lewych górnych rogów, w kolejności wierszami.
+///
+/// Haszowanie kroczące z [`rabin_karp`](super::rabin_karp) stosowane jest dwa
+/// razy: najpierw w każdym wierszu tekstu liczony jest hash każdego okna
+/// długości c, potem w każdej kolumnie tych haszy – hash okna r kolejnych
+/// wierszy, porównywany z haszem wierszy wzorca. Obie podstawy losowane są
+/// z `rng` (modulo 2^61 - 1, patrz [`PolyHash`]), a trafienia są weryfikowane.
+/// Oczekiwany czas to O(RC + rc).
+///
+/// Wzorzec bez wierszy lub kolumn występuje w każdym położeniu, w którym
+/// mieści się w tekście.
+pub fn rabin_karp_2d<R: Rng + ?Sized>(
+    text: &Grid,
+    pattern: &Grid,
+    rng: &mut R,
+) -> Vec<(usize, usize)> {
+    let (pr, pc) = (pattern.rows, pattern.cols);
+    let (tr, tc) = (text.rows, text.cols);
+    let mut wynik = Vec::new();
+    if pr > tr || pc > tc {
+        return wynik;
+    }
+    if pr == 0 || pc == 0 {
+        for i in 0..=(tr - pr) {
+            wynik.extend((0..=(tc - pc)).map(|j| (i, j)));
+        }
+        return wynik;
+    }
+
+    let row_hash = PolyHash::random(rng);
+    let col_hash = PolyHash::random(rng);
+
+    // Hash wzorca: hash (po kolumnie) haszy jego wierszy.
+    let pattern_hash = (0..pr).fold(0, |acc, k| {
+        col_hash.push(acc, row_hash.hash_bytes(pattern.row(k)))
+    });
+
+    // rh[i * w + j] – hash okna text.row(i)[j..j + pc].
+    let w = tc - pc + 1;
+    let row_pow = row_hash.pow(pc - 1);
+    let mut rh = vec![0u64; tr * w];
+    for i in 0..tr {
+        let row = text.row(i);
+        let mut h = row_hash.hash_bytes(&row[..pc]);
+        for j in 0..w {
+            rh[i * w + j] = h;
+            if j + 1 < w {
+                h = row_hash.roll(h, row[j] as u64, row[j + pc] as u64, row_pow);
+            }
+        }
+    }
+
+    // Hash okna pr kolejnych wierszy w każdej kolumnie, przesuwany w dół.
+    let col_pow = col_hash.pow(pr - 1);
+    let mut ch: Vec<u64> = (0..w)
+        .map(|j| (0..pr).fold(0, |acc, k| col_hash.push(acc, rh[k * w + j])))
+        .collect();
+    for i in 0..=(tr - pr) {
+        for j in 0..w {
+            if ch[j] == pattern_hash
+                && (0..pr).all(|k| text.row(i + k)[j..j + pc] == *pattern.row(k))
+            {
+                wynik.push((i, j));
+            }
+            if i < tr - pr {
+                ch[j] = col_hash.roll(ch[j], rh[i * w + j], rh[(i + pr) * w + j], col_pow);
+            }
+        }
+    }
+
+    wynik
+}
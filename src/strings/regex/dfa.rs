@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use super::nfa::{Nfa, Node, find_all_with, in_ranges};
+use crate::Result;
+use crate::strings::minimize::Automaton;
+
+/// Deterministyczny automat skończony otrzymany z [`Nfa`] konstrukcją
+/// podzbiorów.
+///
+/// Przejścia mają ten sam kształt co w
+/// [`build_prefix_automaton`](crate::strings::build_prefix_automaton):
+/// `Vec<HashMap<char, usize>>`, stan początkowy to 0, a brak przejścia
+/// oznacza, że dopasowanie nie może być kontynuowane. Klasy znaków mogą
+/// obejmować ogromne zakresy (np. `.`), dlatego alfabet dzielony jest na
+/// przedziały znaków, których automat nie rozróżnia; klucze map to pierwsze
+/// znaki tych przedziałów, a [`next_state`](Self::next_state) przed
+/// wyszukaniem w mapie zamienia znak na reprezentanta jego przedziału.
+#[derive(Debug, Clone)]
+pub struct Dfa {
+    transitions: Vec<HashMap<char, usize>>,
+    accepting: Vec<bool>,
+    /// Posortowane początki przedziałów alfabetu (pierwszy to `'\0'`).
+    classes: Vec<char>,
+}
+
+impl Dfa {
+    /// Parsuje wyrażenie i buduje automat (przez [`Nfa`]).
+    pub fn new(pattern: &str) -> Result<Self> {
+        Ok(Self::from_nfa(&Nfa::new(pattern)?))
+    }
+
+    /// Konstrukcja podzbiorów: stan DFA to domknięty na przejścia ε zbiór
+    /// węzłów NFA. Tworzone są tylko stany osiągalne ze stanu początkowego,
+    /// ale w najgorszym przypadku jest ich wykładniczo wiele względem NFA.
+    pub fn from_nfa(nfa: &Nfa) -> Self {
+        let classes = alphabet_classes(nfa);
+        let mut seen = vec![false; nfa.nodes.len()];
+        let mut initial = Vec::new();
+        nfa.closure(nfa.start, &mut initial, &mut seen);
+        initial.sort_unstable();
+
+        let mut ids: HashMap<Vec<usize>, usize> = HashMap::from([(initial.clone(), 0)]);
+        let mut sets = vec![initial];
+        let mut transitions = Vec::new();
+        let mut accepting = Vec::new();
+        let mut i = 0;
+        while i < sets.len() {
+            let mut row = HashMap::new();
+            for &c in &classes {
+                seen.fill(false);
+                let mut next = Vec::new();
+                for &v in &sets[i] {
+                    if let Node::Class(ranges, to) = &nfa.nodes[v]
+                        && in_ranges(ranges, c)
+                    {
+                        nfa.closure(*to, &mut next, &mut seen);
+                    }
+                }
+                if next.is_empty() {
+                    continue;
+                }
+                next.sort_unstable();
+                let id = *ids.entry(next.clone()).or_insert_with(|| {
+                    sets.push(next);
+                    sets.len() - 1
+                });
+                row.insert(c, id);
+            }
+            accepting.push(sets[i].iter().any(|&v| matches!(nfa.nodes[v], Node::Match)));
+            transitions.push(row);
+            i += 1;
+        }
+        Dfa {
+            transitions,
+            accepting,
+            classes,
+        }
+    }
+
+    /// Składa automat z gotowych tablic (np. po minimalizacji); `classes` to
+    /// posortowane początki przedziałów alfabetu, zaczynające się od `'\0'`.
+    pub(crate) fn from_parts(
+        transitions: Vec<HashMap<char, usize>>,
+        accepting: Vec<bool>,
+        classes: Vec<char>,
+    ) -> Self {
+        Dfa {
+            transitions,
+            accepting,
+            classes,
+        }
+    }
+
+    /// Tablica przejść (klucze to reprezentanci przedziałów alfabetu).
+    pub fn transitions(&self) -> &[HashMap<char, usize>] {
+        &self.transitions
+    }
+
+    /// Liczba stanów automatu.
+    pub fn states(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Czy stan `state` jest akceptujący.
+    pub fn is_accepting(&self, state: usize) -> bool {
+        self.accepting[state]
+    }
+
+    /// Stan po wczytaniu znaku `c` w stanie `state` albo `None`, jeśli
+    /// dopasowania nie da się kontynuować.
+    pub fn next_state(&self, state: usize, c: char) -> Option<usize> {
+        let class = self.classes[self.classes.partition_point(|&s| s <= c) - 1];
+        self.transitions[state].get(&class).copied()
+    }
+
+    /// Czy cały `text` pasuje do wyrażenia; czas O(n log σ).
+    pub fn is_full_match(&self, text: &str) -> bool {
+        text.chars()
+            .try_fold(0, |state, c| self.next_state(state, c))
+            .is_some_and(|state| self.accepting[state])
+    }
+
+    /// Najdłuższe dopasowanie zaczynające się dokładnie na pozycji `start`.
+    fn longest_at(&self, text: &str, start: usize) -> Option<usize> {
+        let mut state = 0;
+        let mut end = self.accepting[0].then_some(start);
+        for (i, c) in text[start..].char_indices() {
+            match self.next_state(state, c) {
+                Some(next) => state = next,
+                None => break,
+            }
+            if self.accepting[state] {
+                end = Some(start + i + c.len_utf8());
+            }
+        }
+        end
+    }
+
+    /// Rozłączne dopasowania (zakresy w bajtach) od lewej do prawej; z tych
+    /// samych początków wybierane jest najdłuższe, tak jak w
+    /// [`Nfa::find_all`]. Kolejne początki sprawdzane są automatem
+    /// zakotwiczonym, więc w najgorszym przypadku czas jest kwadratowy.
+    pub fn find_all(&self, text: &str) -> Vec<Range<usize>> {
+        find_all_with(text, |from| {
+            text[from..]
+                .char_indices()
+                .map(|(i, _)| from + i)
+                .chain(std::iter::once(text.len()))
+                .find_map(|start| self.longest_at(text, start).map(|end| start..end))
+        })
+    }
+}
+
+impl Automaton for Dfa {
+    fn state_count(&self) -> usize {
+        self.states()
+    }
+
+    fn is_accepting(&self, state: usize) -> bool {
+        Dfa::is_accepting(self, state)
+    }
+
+    fn next_state(&self, state: usize, c: char) -> Option<usize> {
+        Dfa::next_state(self, state, c)
+    }
+
+    fn alphabet(&self) -> Vec<char> {
+        self.classes.clone()
+    }
+}
+
+/// Początki przedziałów alfabetu, w których wszystkie znaki należą do tych
+/// samych klas NFA: granicami są początki przedziałów i znaki tuż za ich końcami.
+fn alphabet_classes(nfa: &Nfa) -> Vec<char> {
+    let mut starts = vec!['\0'];
+    for node in &nfa.nodes {
+        if let Node::Class(ranges, _) = node {
+            for &(lo, hi) in ranges {
+                starts.push(lo);
+                // Za ostatnim surogatem wypada pierwszy poprawny znak.
+                let after = (hi as u32 + 1..=char::MAX as u32).find_map(char::from_u32);
+                starts.extend(after);
+            }
+        }
+    }
+    starts.sort_unstable();
+    starts.dedup();
+    starts
+}
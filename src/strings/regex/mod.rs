@@ -0,0 +1,18 @@
+//! Proste wyrażenia regularne: parser, automat Thompsona (NFA) z symulacją
+//! zbiorami stanów oraz konstrukcja podzbiorów do DFA o przejściach
+//! `Vec<HashMap<char, usize>>`, jak w automacie prefiksowym.
+//!
+//! - [`parse`] – drzewo składniowe [`Ast`],
+//! - [`Nfa`] – konstrukcja Thompsona i symulacja,
+//! - [`Dfa`] – konstrukcja podzbiorów i wyszukiwanie automatem deterministycznym.
+//!
+//! Oba automaty zwracają te same dopasowania: rozłączne, od lewej do prawej,
+//! a spośród zaczynających się na tej samej pozycji – najdłuższe.
+
+mod dfa;
+mod nfa;
+mod parser;
+
+pub use dfa::Dfa;
+pub use nfa::Nfa;
+pub use parser::{Ast, parse};
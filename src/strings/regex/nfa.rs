@@ -0,0 +1,242 @@
+use std::ops::Range;
+
+use super::parser::{Ast, parse};
+use crate::Result;
+
+/// Węzeł automatu Thompsona.
+#[derive(Debug, Clone)]
+pub(super) enum Node {
+    /// Przejście po znaku z któregoś z przedziałów.
+    Class(Vec<(char, char)>, usize),
+    /// Dwa przejścia ε.
+    Split(usize, usize),
+    /// Jedno przejście ε.
+    Jump(usize),
+    Match,
+}
+
+/// Niedeterministyczny automat skończony z konstrukcji Thompsona.
+///
+/// Każdy operator dodaje stałą liczbę węzłów, więc automat ma O(m) stanów
+/// dla wyrażenia długości `m`, a każdy węzeł ma co najwyżej dwa przejścia.
+#[derive(Debug, Clone)]
+pub struct Nfa {
+    pub(super) nodes: Vec<Node>,
+    pub(super) start: usize,
+}
+
+impl Nfa {
+    /// Parsuje wyrażenie (patrz [`parse`](super::parse)) i buduje automat.
+    pub fn new(pattern: &str) -> Result<Self> {
+        Ok(Self::from_ast(&parse(pattern)?))
+    }
+
+    /// Buduje automat z drzewa składniowego.
+    pub fn from_ast(ast: &Ast) -> Self {
+        let mut nfa = Nfa {
+            nodes: vec![Node::Match],
+            start: 0,
+        };
+        nfa.start = nfa.compile(ast, 0);
+        nfa
+    }
+
+    /// Liczba węzłów automatu.
+    pub fn states(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn push(&mut self, node: Node) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    /// Dodaje węzły rozpoznające `ast`, po których automat przechodzi do
+    /// `next`; zwraca węzeł początkowy.
+    fn compile(&mut self, ast: &Ast, next: usize) -> usize {
+        match ast {
+            Ast::Empty => next,
+            Ast::Literal(c) => self.push(Node::Class(vec![(*c, *c)], next)),
+            Ast::Class(ranges) => self.push(Node::Class(ranges.clone(), next)),
+            Ast::Concat(items) => items
+                .iter()
+                .rev()
+                .fold(next, |next, item| self.compile(item, next)),
+            Ast::Alternation(branches) => {
+                let starts: Vec<usize> = branches.iter().map(|b| self.compile(b, next)).collect();
+                starts
+                    .into_iter()
+                    .rev()
+                    .reduce(|rest, first| self.push(Node::Split(first, rest)))
+                    .unwrap_or(next)
+            }
+            Ast::Star(inner) => {
+                // Węzeł pętli uzupełniamy, gdy znamy już początek ciała.
+                let split = self.push(Node::Jump(next));
+                let body = self.compile(inner, split);
+                self.nodes[split] = Node::Split(body, next);
+                split
+            }
+            Ast::Plus(inner) => {
+                let split = self.push(Node::Jump(next));
+                let body = self.compile(inner, split);
+                self.nodes[split] = Node::Split(body, next);
+                body
+            }
+            Ast::Optional(inner) => {
+                let body = self.compile(inner, next);
+                self.push(Node::Split(body, next))
+            }
+        }
+    }
+
+    /// Dodaje do `set` domknięcie ε węzła `node`.
+    pub(super) fn closure(&self, node: usize, set: &mut Vec<usize>, seen: &mut [bool]) {
+        let mut stack = vec![node];
+        while let Some(v) = stack.pop() {
+            if std::mem::replace(&mut seen[v], true) {
+                continue;
+            }
+            set.push(v);
+            match self.nodes[v] {
+                Node::Split(a, b) => stack.extend([b, a]),
+                Node::Jump(a) => stack.push(a),
+                Node::Class(..) | Node::Match => {}
+            }
+        }
+    }
+
+    /// Czy cały `text` pasuje do wyrażenia (symulacja zbiorami stanów, O(nm)).
+    pub fn is_full_match(&self, text: &str) -> bool {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut current = Vec::new();
+        self.closure(self.start, &mut current, &mut seen);
+        for c in text.chars() {
+            seen.fill(false);
+            let mut next = Vec::new();
+            for &v in &current {
+                if let Node::Class(ranges, to) = &self.nodes[v]
+                    && in_ranges(ranges, c)
+                {
+                    self.closure(*to, &mut next, &mut seen);
+                }
+            }
+            if next.is_empty() {
+                return false;
+            }
+            current = next;
+        }
+        current
+            .iter()
+            .any(|&v| matches!(self.nodes[v], Node::Match))
+    }
+
+    /// Najbardziej lewe, a wśród nich najdłuższe dopasowanie zaczynające się
+    /// nie wcześniej niż `from`.
+    ///
+    /// Symulacja w stylu Pike'a: każdy aktywny węzeł pamięta najwcześniejszy
+    /// początek, z którego da się do niego dojść. Nowe wątki startują na
+    /// kolejnych pozycjach tylko do znalezienia pierwszego dopasowania, potem
+    /// odrzucane są wątki zaczynające się później niż najlepsze dopasowanie.
+    /// Czas O(nm) na wywołanie.
+    fn find_at(&self, text: &str, from: usize) -> Option<Range<usize>> {
+        let mut start_of: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut current: Vec<usize> = Vec::new();
+        let mut best: Option<Range<usize>> = None;
+        let mut pos = from;
+        loop {
+            if best.is_none() {
+                self.add_thread(self.start, pos, &mut current, &mut start_of);
+            }
+            if let Some(s) = start_of[0] {
+                // Węzeł 0 to `Match`.
+                if best.as_ref().is_none_or(|b| s <= b.start) {
+                    best = Some(s..pos);
+                }
+            }
+            if let Some(b) = &best {
+                current.retain(|&v| start_of[v].is_some_and(|s| s <= b.start));
+            }
+            let Some(c) = text[pos..].chars().next() else {
+                break;
+            };
+            if best.is_some() && current.is_empty() {
+                break;
+            }
+
+            let mut next_start: Vec<Option<usize>> = vec![None; self.nodes.len()];
+            let mut next = Vec::new();
+            for &v in &current {
+                if let Node::Class(ranges, to) = &self.nodes[v]
+                    && in_ranges(ranges, c)
+                {
+                    let s = start_of[v].expect("aktywny węzeł ma początek");
+                    self.add_thread(*to, s, &mut next, &mut next_start);
+                }
+            }
+            current = next;
+            start_of = next_start;
+            pos += c.len_utf8();
+        }
+        best
+    }
+
+    /// Dodaje domknięcie ε węzła `node` z początkiem `start`; węzeł już
+    /// obecny z wcześniejszym (lub tym samym) początkiem nie jest zmieniany.
+    fn add_thread(
+        &self,
+        node: usize,
+        start: usize,
+        list: &mut Vec<usize>,
+        start_of: &mut [Option<usize>],
+    ) {
+        let mut stack = vec![node];
+        while let Some(v) = stack.pop() {
+            match start_of[v] {
+                Some(s) if s <= start => continue,
+                Some(_) => {}
+                None => list.push(v),
+            }
+            start_of[v] = Some(start);
+            match self.nodes[v] {
+                Node::Split(a, b) => stack.extend([b, a]),
+                Node::Jump(a) => stack.push(a),
+                Node::Class(..) | Node::Match => {}
+            }
+        }
+    }
+
+    /// Rozłączne dopasowania (zakresy w bajtach) od lewej do prawej; z tych
+    /// samych początków wybierane jest najdłuższe. Po pustym dopasowaniu
+    /// wyszukiwanie przesuwa się o jeden znak.
+    pub fn find_all(&self, text: &str) -> Vec<Range<usize>> {
+        find_all_with(text, |from| self.find_at(text, from))
+    }
+}
+
+/// Czy `c` należy do któregoś z posortowanych przedziałów.
+pub(super) fn in_ranges(ranges: &[(char, char)], c: char) -> bool {
+    let i = ranges.partition_point(|&(lo, _)| lo <= c);
+    i > 0 && c <= ranges[i - 1].1
+}
+
+/// Kolejne rozłączne dopasowania przy danej funkcji szukającej od pozycji.
+pub(super) fn find_all_with(
+    text: &str,
+    mut find_at: impl FnMut(usize) -> Option<Range<usize>>,
+) -> Vec<Range<usize>> {
+    let mut wynik = Vec::new();
+    let mut pos = 0;
+    while pos <= text.len() {
+        let Some(m) = find_at(pos) else {
+            break;
+        };
+        pos = if m.is_empty() {
+            m.end + text[m.end..].chars().next().map_or(1, char::len_utf8)
+        } else {
+            m.end
+        };
+        wynik.push(m);
+    }
+    wynik
+}
@@ -0,0 +1,212 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::{Error, Result};
+
+/// Największy kod znaku Unicode.
+const MAX_CHAR: u32 = char::MAX as u32;
+/// Zakres surogatów UTF-16, które nie są poprawnymi znakami.
+const SURROGATES: (u32, u32) = (0xD800, 0xDFFF);
+
+/// Drzewo składniowe wyrażenia regularnego.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ast {
+    /// Słowo puste (np. pusta gałąź alternatywy).
+    Empty,
+    Literal(char),
+    /// Zbiór znaków jako posortowane, rozłączne i niesąsiadujące przedziały
+    /// domknięte; `.` i klasy zanegowane są tu już rozwinięte.
+    Class(Vec<(char, char)>),
+    Concat(Vec<Ast>),
+    Alternation(Vec<Ast>),
+    /// `e*`
+    Star(Box<Ast>),
+    /// `e+`
+    Plus(Box<Ast>),
+    /// `e?`
+    Optional(Box<Ast>),
+}
+
+/// Parsuje wyrażenie regularne. Obsługiwana składnia:
+///
+/// - konkatenacja `ab`, alternatywa `a|b`, grupowanie `(a|b)c`,
+/// - powtórzenia `e*`, `e+`, `e?`,
+/// - `.` – dowolny znak,
+/// - klasy `[abc]`, `[a-z0-9]`, `[^ab]`,
+/// - `\` poprzedza znak brany dosłownie (`\n` i `\t` to nowa linia i tabulacja).
+///
+/// Zwraca [`Error::InvalidRegex`] z pozycją (w bajtach) pierwszego błędu.
+pub fn parse(pattern: &str) -> Result<Ast> {
+    let mut parser = Parser {
+        chars: pattern.char_indices().peekable(),
+    };
+    let ast = parser.alternation()?;
+    match parser.chars.next() {
+        None => Ok(ast),
+        Some((i, _)) => Err(error(i, "niesparowany nawias `)`")),
+    }
+}
+
+fn error(position: usize, reason: &'static str) -> Error {
+    Error::InvalidRegex { position, reason }
+}
+
+struct Parser<'p> {
+    chars: Peekable<CharIndices<'p>>,
+}
+
+impl Parser<'_> {
+    /// alternation := concat ('|' concat)*
+    fn alternation(&mut self) -> Result<Ast> {
+        let mut branches = vec![self.concat()?];
+        while self.chars.next_if(|&(_, c)| c == '|').is_some() {
+            branches.push(self.concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            Ast::Alternation(branches)
+        })
+    }
+
+    /// concat := repeat*
+    fn concat(&mut self) -> Result<Ast> {
+        let mut items = Vec::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            items.push(self.repeat()?);
+        }
+        Ok(match items.len() {
+            0 => Ast::Empty,
+            1 => items.pop().unwrap(),
+            _ => Ast::Concat(items),
+        })
+    }
+
+    /// repeat := atom ('*' | '+' | '?')*
+    fn repeat(&mut self) -> Result<Ast> {
+        let mut ast = self.atom()?;
+        while let Some((_, op)) = self.chars.next_if(|&(_, c)| matches!(c, '*' | '+' | '?')) {
+            ast = match op {
+                '*' => Ast::Star(Box::new(ast)),
+                '+' => Ast::Plus(Box::new(ast)),
+                _ => Ast::Optional(Box::new(ast)),
+            };
+        }
+        Ok(ast)
+    }
+
+    /// atom := znak | '.' | klasa | '(' alternation ')' | '\' znak
+    fn atom(&mut self) -> Result<Ast> {
+        let (i, c) = self.chars.next().expect("concat sprawdza koniec wzorca");
+        match c {
+            '(' => {
+                let inner = self.alternation()?;
+                match self.chars.next() {
+                    Some((_, ')')) => Ok(inner),
+                    _ => Err(error(i, "niezamknięty nawias `(`")),
+                }
+            }
+            '[' => self.class(i),
+            '.' => Ok(Ast::Class(char_ranges(0, MAX_CHAR))),
+            '\\' => self.escaped(i).map(Ast::Literal),
+            '*' | '+' | '?' => Err(error(i, "brak wyrażenia do powtórzenia")),
+            ']' => Err(error(i, "niesparowany nawias `]`")),
+            c => Ok(Ast::Literal(c)),
+        }
+    }
+
+    /// Znak po `\` (na pozycji `i`).
+    fn escaped(&mut self, i: usize) -> Result<char> {
+        match self.chars.next() {
+            Some((_, 'n')) => Ok('\n'),
+            Some((_, 't')) => Ok('\t'),
+            Some((_, c)) => Ok(c),
+            None => Err(error(i, "`\\` na końcu wzorca")),
+        }
+    }
+
+    /// Klasa znaków; `[` stoi na pozycji `start`.
+    fn class(&mut self, start: usize) -> Result<Ast> {
+        let negated = self.chars.next_if(|&(_, c)| c == '^').is_some();
+        let mut ranges = Vec::new();
+        loop {
+            let (i, c) = self
+                .chars
+                .next()
+                .ok_or(error(start, "niezamknięta klasa znaków"))?;
+            let lo = match c {
+                ']' => break,
+                '\\' => self.escaped(i)?,
+                c => c,
+            };
+            // `-` tworzy przedział, chyba że stoi tuż przed `]`.
+            let is_range = self.chars.peek().is_some_and(|&(_, c)| c == '-') && {
+                let mut ahead = self.chars.clone();
+                ahead.next();
+                ahead.peek().is_some_and(|&(_, c)| c != ']')
+            };
+            if !is_range {
+                ranges.push((lo, lo));
+                continue;
+            }
+            self.chars.next();
+            let (j, c) = self.chars.next().expect("sprawdzone wyżej");
+            let hi = if c == '\\' { self.escaped(j)? } else { c };
+            if lo > hi {
+                return Err(error(i, "odwrócony przedział w klasie znaków"));
+            }
+            ranges.push((lo, hi));
+        }
+        if ranges.is_empty() {
+            return Err(error(start, "pusta klasa znaków"));
+        }
+        let ranges = normalize(ranges);
+        Ok(Ast::Class(if negated {
+            complement(&ranges)
+        } else {
+            ranges
+        }))
+    }
+}
+
+/// Sortuje przedziały i scala nakładające się lub sąsiednie.
+fn normalize(mut ranges: Vec<(char, char)>) -> Vec<(char, char)> {
+    ranges.sort_unstable();
+    let mut wynik: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+    for (lo, hi) in ranges {
+        match wynik.last_mut() {
+            Some(last) if lo as u32 <= last.1 as u32 + 1 => last.1 = last.1.max(hi),
+            _ => wynik.push((lo, hi)),
+        }
+    }
+    wynik
+}
+
+/// Dopełnienie znormalizowanego zbioru przedziałów.
+fn complement(ranges: &[(char, char)]) -> Vec<(char, char)> {
+    let mut wynik = Vec::new();
+    let mut next = 0;
+    for &(lo, hi) in ranges {
+        if next < lo as u32 {
+            wynik.extend(char_ranges(next, lo as u32 - 1));
+        }
+        next = hi as u32 + 1;
+    }
+    if next <= MAX_CHAR {
+        wynik.extend(char_ranges(next, MAX_CHAR));
+    }
+    wynik
+}
+
+/// Znaki z przedziału kodów `lo..=hi` jako przedziały `char` (bez surogatów).
+fn char_ranges(lo: u32, hi: u32) -> Vec<(char, char)> {
+    let (s_lo, s_hi) = SURROGATES;
+    [(lo, hi.min(s_lo - 1)), (lo.max(s_hi + 1), hi)]
+        .into_iter()
+        .filter(|&(a, b)| a <= b)
+        .map(|(a, b)| (char::from_u32(a).unwrap(), char::from_u32(b).unwrap()))
+        .collect()
+}
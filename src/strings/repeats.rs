@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use super::hashing::{PolyHash, RollingHashIndex};
+use crate::{Error, Result};
+
+/// Najdłuższe powtórzone podsłowo tekstu wraz z pozycjami (w bajtach)
+/// wszystkich jego wystąpień, łącznie z nakładającymi się.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatedSubstring {
+    pub substring: String,
+    pub positions: Vec<usize>,
+}
+
+/// Najdłuższe wspólne podsłowo kilku tekstów; `positions[k]` to pozycja
+/// (w bajtach) pierwszego wystąpienia w `k`-tym tekście.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommonSubstring {
+    pub substring: String,
+    pub positions: Vec<usize>,
+}
+
+/// Tekst przygotowany do wyszukiwania: znaki, ich pozycje w bajtach i indeks haszy.
+struct Indexed<'a> {
+    text: &'a str,
+    chars: Vec<u32>,
+    offsets: Vec<usize>, // offsets[i] – pozycja i-tego znaku w bajtach, offsets[n] = text.len()
+    index: RollingHashIndex,
+}
+
+impl<'a> Indexed<'a> {
+    fn new(text: &'a str, hashers: [PolyHash; 2]) -> Self {
+        let chars: Vec<u32> = text.chars().map(u32::from).collect();
+        let offsets = text
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(text.len()))
+            .collect();
+        let index = RollingHashIndex::with_hashers(&chars, hashers);
+        Indexed {
+            text,
+            chars,
+            offsets,
+            index,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    fn window(&self, i: usize, len: usize) -> &[u32] {
+        &self.chars[i..i + len]
+    }
+
+    fn substring(&self, i: usize, len: usize) -> &'a str {
+        &self.text[self.offsets[i]..self.offsets[i + len]]
+    }
+}
+
+/// Największe `l` z przedziału `[lo, hi]`, dla którego `check(l)` zwraca
+/// `Some`, przy założeniu monotoniczności (jeśli `l` pasuje, pasuje też `l - 1`).
+fn binary_search_longest<T>(
+    mut lo: usize,
+    mut hi: usize,
+    mut check: impl FnMut(usize) -> Option<T>,
+) -> Option<(usize, T)> {
+    let mut best = None;
+    while lo <= hi {
+        let mid = lo + (hi - lo) / 2;
+        match check(mid) {
+            Some(found) => {
+                best = Some((mid, found));
+                lo = mid + 1;
+            }
+            None if mid == 0 => break,
+            None => hi = mid - 1,
+        }
+    }
+    best
+}
+
+/// Pozycja (w znakach) pewnego powtórzonego podsłowa długości `len`, jeśli istnieje.
+/// Okna o równych haszach są porównywane znak po znaku, więc kolizja haszy nie
+/// może dać fałszywego wyniku ani ukryć prawdziwego powtórzenia.
+fn find_repeat(t: &Indexed, len: usize) -> Option<usize> {
+    // hash -> pozycje parami różnych okien o tym haszu
+    let mut seen: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for i in 0..=(t.len() - len) {
+        let bucket = seen.entry(t.index.hash(i..i + len)).or_default();
+        if bucket.iter().any(|&j| t.window(j, len) == t.window(i, len)) {
+            return Some(i);
+        }
+        bucket.push(i);
+    }
+    None
+}
+
+/// Najdłuższe podsłowo występujące w `text` co najmniej dwa razy (wystąpienia
+/// mogą się nakładać), albo `None`, jeśli żaden znak się nie powtarza.
+///
+/// Wyszukiwanie binarne po długości, a dla ustalonej długości – tablica
+/// haszująca haszy wszystkich okien (z [`RollingHashIndex`], podstawy losowane
+/// z `rng`). Równość haszy jest zawsze weryfikowana, więc wynik jest dokładny;
+/// oczekiwany czas to O(n log n).
+pub fn longest_repeated_substring<R: Rng + ?Sized>(
+    text: &str,
+    rng: &mut R,
+) -> Option<RepeatedSubstring> {
+    let t = Indexed::new(text, RollingHashIndex::random_hashers(rng));
+    if t.len() < 2 {
+        return None;
+    }
+    let (len, start) = binary_search_longest(1, t.len() - 1, |len| find_repeat(&t, len))?;
+
+    // Wszystkie wystąpienia znalezionego podsłowa (z weryfikacją).
+    let target = t.index.hash(start..start + len);
+    let positions = (0..=(t.len() - len))
+        .filter(|&i| t.index.hash(i..i + len) == target && t.window(i, len) == t.window(start, len))
+        .map(|i| t.offsets[i])
+        .collect();
+    Some(RepeatedSubstring {
+        substring: t.substring(start, len).to_string(),
+        positions,
+    })
+}
+
+/// Pozycje (w znakach, po jednej na tekst) pewnego wspólnego podsłowa długości
+/// `len` wszystkich tekstów, jeśli istnieje. Kandydaci to parami różne okna
+/// pierwszego tekstu; w kolejnych tekstach zostają tylko te, które
+/// (po weryfikacji) w nich występują.
+fn find_common(texts: &[Indexed], len: usize) -> Option<Vec<usize>> {
+    let first = &texts[0];
+    let mut candidates: HashMap<(u64, u64), Vec<Vec<usize>>> = HashMap::new();
+    for i in 0..=(first.len() - len) {
+        let bucket = candidates.entry(first.index.hash(i..i + len)).or_default();
+        if !bucket
+            .iter()
+            .any(|c| first.window(c[0], len) == first.window(i, len))
+        {
+            bucket.push(vec![i]);
+        }
+    }
+
+    for t in &texts[1..] {
+        let mut matched: HashMap<(u64, u64), Vec<Vec<usize>>> = HashMap::new();
+        for j in 0..=(t.len() - len) {
+            let h = t.index.hash(j..j + len);
+            let Some(bucket) = candidates.get(&h) else {
+                continue;
+            };
+            let done = matched.entry(h).or_default();
+            for c in bucket {
+                let already = done.iter().any(|d| d[0] == c[0]);
+                if !already && first.window(c[0], len) == t.window(j, len) {
+                    let mut c = c.clone();
+                    c.push(j);
+                    done.push(c);
+                }
+            }
+        }
+        matched.retain(|_, bucket| !bucket.is_empty());
+        if matched.is_empty() {
+            return None;
+        }
+        candidates = matched;
+    }
+
+    // Spośród pozostałych kandydatów wybieramy ten, który najwcześniej
+    // występuje w pierwszym tekście, żeby wynik nie zależał od haszy.
+    candidates.into_values().flatten().min()
+}
+
+/// Najdłuższe podsłowo wspólne dla wszystkich `texts` albo `None`, jeśli nie
+/// mają wspólnego znaku.
+///
+/// Wyszukiwanie binarne po długości, a dla ustalonej długości – przecinanie
+/// zbiorów haszy okien kolejnych tekstów (ten sam [`RollingHashIndex`] z
+/// podstawami losowanymi z `rng` dla wszystkich tekstów). Równość haszy jest
+/// zawsze weryfikowana, więc wynik jest dokładny.
+///
+/// Zwraca [`Error::InvalidParameter`] dla mniej niż dwóch tekstów.
+pub fn longest_common_substring<S: AsRef<str>, R: Rng + ?Sized>(
+    texts: &[S],
+    rng: &mut R,
+) -> Result<Option<CommonSubstring>> {
+    if texts.len() < 2 {
+        return Err(Error::InvalidParameter(
+            "potrzebne są co najmniej dwa teksty",
+        ));
+    }
+    let hashers = RollingHashIndex::random_hashers(rng);
+    let indexed: Vec<Indexed> = texts
+        .iter()
+        .map(|t| Indexed::new(t.as_ref(), hashers))
+        .collect();
+    let max_len = indexed.iter().map(Indexed::len).min().unwrap_or(0);
+    if max_len == 0 {
+        return Ok(None);
+    }
+
+    let Some((len, starts)) = binary_search_longest(1, max_len, |len| find_common(&indexed, len))
+    else {
+        return Ok(None);
+    };
+    Ok(Some(CommonSubstring {
+        substring: indexed[0].substring(starts[0], len).to_string(),
+        positions: starts
+            .iter()
+            .zip(&indexed)
+            .map(|(&i, t)| t.offsets[i])
+            .collect(),
+    }))
+}
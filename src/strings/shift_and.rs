@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use super::automaton::{decode_char, next_empty_match};
+use super::matcher::Matcher;
+use crate::{Error, Result};
+
+/// Symbol wzorca dla [`ShiftAnd`]: pozycja wzorca może dopuszczać jeden znak,
+/// zbiór znaków albo dowolny znak.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternSymbol {
+    Char(char),
+    /// Klasa znaków, np. `[AG]`.
+    Class(Vec<char>),
+    /// Pozycja bez ograniczeń (ang. *don't care*).
+    Any,
+}
+
+/// Zbiory znaków dla kodów nukleotydów IUPAC.
+fn iupac_class(code: char) -> Option<&'static str> {
+    Some(match code {
+        'A' => "A",
+        'C' => "C",
+        'G' => "G",
+        'T' => "T",
+        'R' => "AG",
+        'Y' => "CT",
+        'S' => "CG",
+        'W' => "AT",
+        'K' => "GT",
+        'M' => "AC",
+        'B' => "CGT",
+        'D' => "AGT",
+        'H' => "ACT",
+        'V' => "ACG",
+        'N' => "ACGT",
+        _ => return None,
+    })
+}
+
+/// Wyszukiwanie bitowo-równoległe Shift-And (Baeza-Yates–Gonnet).
+///
+/// Stan to wektor bitów: bit `i` jest ustawiony, gdy ostatnie `i + 1` znaków
+/// tekstu pasuje do `i + 1` pierwszych pozycji wzorca. Po wczytaniu znaku `c`
+/// stan przechodzi w `((D << 1) | 1) & B[c]`, gdzie `B[c]` ma ustawione bity
+/// pozycji dopuszczających `c`, a wystąpienie kończy się tam, gdzie ustawiony
+/// jest bit `m - 1`. Shift-Or to ten sam algorytm na zanegowanych bitach.
+///
+/// Dzięki maskom pozycja może dopuszczać klasę znaków (np. `[AG]` albo kod
+/// IUPAC `R`) lub dowolny znak, czego automat prefiksowy nie wyraża.
+/// Wzorce dłuższe niż 64 zajmują kilka słów `u64`; krok kosztuje O(⌈m/64⌉).
+#[derive(Debug, Clone)]
+pub struct ShiftAnd {
+    /// Maski znaków występujących we wzorcu (z dołączonymi bitami `any`).
+    masks: HashMap<char, Vec<u64>>,
+    /// Bity pozycji dopuszczających dowolny znak – maska pozostałych znaków.
+    any: Vec<u64>,
+    /// Długość wzorca w znakach.
+    m: usize,
+}
+
+impl ShiftAnd {
+    /// Przygotowuje wzorzec z podanych symboli.
+    pub fn new(symbols: &[PatternSymbol]) -> Self {
+        let m = symbols.len();
+        let words = m.div_ceil(64);
+        let mut any = vec![0u64; words];
+        for (i, s) in symbols.iter().enumerate() {
+            if *s == PatternSymbol::Any {
+                any[i / 64] |= 1 << (i % 64);
+            }
+        }
+        let mut masks: HashMap<char, Vec<u64>> = HashMap::new();
+        for (i, s) in symbols.iter().enumerate() {
+            let chars = match s {
+                PatternSymbol::Char(c) => std::slice::from_ref(c),
+                PatternSymbol::Class(cs) => cs.as_slice(),
+                PatternSymbol::Any => &[],
+            };
+            for &c in chars {
+                masks.entry(c).or_insert_with(|| any.clone())[i / 64] |= 1 << (i % 64);
+            }
+        }
+        ShiftAnd { masks, any, m }
+    }
+
+    /// Przygotowuje wzorzec zapisany z klasami: `[AG]` dopuszcza jeden ze
+    /// znaków w nawiasach, `.` dowolny znak, a `\` poprzedza znak brany
+    /// dosłownie (np. `\.` albo `\[`).
+    ///
+    /// Zwraca [`Error::InvalidParameter`] dla niezamkniętej lub pustej klasy
+    /// i dla `\` na końcu wzorca.
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let mut symbols = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            symbols.push(match c {
+                '.' => PatternSymbol::Any,
+                '\\' => PatternSymbol::Char(
+                    chars
+                        .next()
+                        .ok_or(Error::InvalidParameter("`\\` na końcu wzorca"))?,
+                ),
+                '[' => {
+                    let mut class = Vec::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) => class.push(c),
+                            None => {
+                                return Err(Error::InvalidParameter("niezamknięta klasa znaków"));
+                            }
+                        }
+                    }
+                    if class.is_empty() {
+                        return Err(Error::InvalidParameter("pusta klasa znaków"));
+                    }
+                    PatternSymbol::Class(class)
+                }
+                c => PatternSymbol::Char(c),
+            });
+        }
+        Ok(Self::new(&symbols))
+    }
+
+    /// Przygotowuje wzorzec DNA zapisany kodami IUPAC (`A`, `C`, `G`, `T`,
+    /// `R` = `[AG]`, `Y` = `[CT]`, …, `N` = `[ACGT]`).
+    ///
+    /// Zwraca [`Error::InvalidSymbol`] dla znaku spoza kodów IUPAC.
+    pub fn iupac(pattern: &str) -> Result<Self> {
+        let symbols = pattern
+            .chars()
+            .map(|c| {
+                let class = iupac_class(c).ok_or(Error::InvalidSymbol(c))?;
+                Ok(PatternSymbol::Class(class.chars().collect()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(&symbols))
+    }
+
+    /// Długość wzorca w znakach.
+    pub fn m(&self) -> usize {
+        self.m
+    }
+}
+
+impl Matcher for ShiftAnd {
+    type Iter<'a, 't> = ShiftAndMatches<'a, 't>;
+
+    /// Każdy znak `pattern` traktowany jest dosłownie; klasy i pozycje
+    /// dowolne tworzą [`ShiftAnd::parse`] i [`ShiftAnd::new`].
+    fn compile(pattern: &str) -> Self {
+        let symbols: Vec<PatternSymbol> = pattern.chars().map(PatternSymbol::Char).collect();
+        ShiftAnd::new(&symbols)
+    }
+
+    fn find_iter<'a, 't, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'t H,
+    ) -> ShiftAndMatches<'a, 't> {
+        ShiftAndMatches {
+            shift_and: self,
+            haystack: haystack.as_ref(),
+            pos: 0,
+            state: vec![0; self.any.len()],
+            starts: vec![0; self.m],
+            chars: 0,
+            done: false,
+        }
+    }
+}
+
+/// Iterator po pozycjach wystąpień wzorca zwracany przez [`Matcher::find_iter`].
+///
+/// Dopasowany fragment może mieć inną długość w bajtach niż wzorzec (klasy,
+/// pozycje dowolne), więc iterator pamięta początki ostatnich `m` znaków.
+/// Niepoprawne bajty UTF-8 nie pasują do żadnej pozycji wzorca.
+#[derive(Debug, Clone)]
+pub struct ShiftAndMatches<'a, 't> {
+    shift_and: &'a ShiftAnd,
+    haystack: &'t [u8],
+    pos: usize,
+    state: Vec<u64>,
+    /// Bufor cykliczny początków (w bajtach) ostatnich `m` znaków.
+    starts: Vec<usize>,
+    /// Liczba wczytanych znaków.
+    chars: usize,
+    done: bool,
+}
+
+impl Iterator for ShiftAndMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+        let m = self.shift_and.m;
+        if m == 0 {
+            return next_empty_match(self.haystack, &mut self.pos, &mut self.done);
+        }
+        while self.pos < self.haystack.len() {
+            let (c, len) = decode_char(&self.haystack[self.pos..]);
+            match c {
+                Some(c) => {
+                    let mask = self.shift_and.masks.get(&c).unwrap_or(&self.shift_and.any);
+                    // (D << 1) | 1 na wielu słowach: najstarszy bit słowa przechodzi do następnego.
+                    let mut carry = 1;
+                    for (d, &b) in self.state.iter_mut().zip(mask) {
+                        let shifted = (*d << 1) | carry;
+                        carry = *d >> 63;
+                        *d = shifted & b;
+                    }
+                }
+                None => self.state.fill(0),
+            }
+            self.starts[self.chars % m] = self.pos;
+            self.chars += 1;
+            self.pos += len;
+            if self.state[(m - 1) / 64] >> ((m - 1) % 64) & 1 == 1 {
+                return Some(self.starts[self.chars % m]);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
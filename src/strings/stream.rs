@@ -0,0 +1,191 @@
+use std::io::{self, ErrorKind, Read};
+
+use super::automaton::build_byte_table;
+use super::rabin_karp::RabinKarp;
+
+/// Domyślny rozmiar porcji wczytywanej jednorazowo ze strumienia.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Algorytm używany przez [`StreamMatches`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamAlgorithm {
+    /// Automat prefiksowy na bajtach (tablica `[stan * 256 + bajt]`); między
+    /// porcjami pamiętany jest tylko bieżący stan.
+    #[default]
+    Automaton,
+    /// Rabin–Karp; między porcjami pamiętany jest hash okna i ostatnie m bajtów
+    /// (potrzebne do przesunięcia okna i weryfikacji trafienia).
+    RabinKarp,
+}
+
+#[derive(Debug, Clone)]
+enum Engine {
+    // Pusty wzorzec: wystąpienie na każdej granicy znaku.
+    Empty,
+    Automaton {
+        table: Vec<usize>,
+        m: usize,
+        state: usize,
+    },
+    RabinKarp {
+        rk: RabinKarp,
+        hash_text: u64,
+        window: Vec<u8>, // bufor cykliczny ostatnich m bajtów
+    },
+}
+
+impl Engine {
+    /// Przetwarza bajt `b` o pozycji bezwzględnej `pos`; zwraca początek
+    /// wystąpienia kończącego się na tym bajcie.
+    fn step(&mut self, b: u8, pos: u64) -> Option<u64> {
+        match self {
+            // Bajty kontynuacji UTF-8 (10xxxxxx) nie zaczynają znaku.
+            Engine::Empty => (b & 0xC0 != 0x80).then_some(pos),
+            Engine::Automaton { table, m, state } => {
+                *state = table[*state * 256 + b as usize];
+                (*state == *m).then(|| pos + 1 - *m as u64)
+            }
+            Engine::RabinKarp {
+                rk,
+                hash_text,
+                window,
+            } => {
+                let m = window.len();
+                let slot = (pos % m as u64) as usize;
+                if pos < m as u64 {
+                    *hash_text = RabinKarp::push(*hash_text, b);
+                } else {
+                    *hash_text = rk.roll(*hash_text, window[slot], b);
+                }
+                window[slot] = b;
+                if pos + 1 < m as u64 || *hash_text != rk.hash_pattern() {
+                    return None;
+                }
+                // Okno zaczyna się w buforze cyklicznym na pozycji slot + 1.
+                let start = (slot + 1) % m;
+                let (p1, p2) = rk.pattern().split_at(m - start);
+                (window[start..] == *p1 && window[..start] == *p2).then(|| pos + 1 - m as u64)
+            }
+        }
+    }
+}
+
+/// Iterator po pozycjach (bezwzględnych, w bajtach) początków wszystkich
+/// wystąpień wzorca w strumieniu, łącznie z nakładającymi się.
+///
+/// Strumień czytany jest porcjami, więc tekst nie musi mieścić się w pamięci;
+/// stan algorytmu przechodzi między porcjami, dzięki czemu znajdowane są też
+/// wystąpienia przecinające granicę porcji. Błąd odczytu kończy iterację.
+///
+/// Pusty wzorzec występuje na każdej granicy znaku (dla poprawnego UTF-8),
+/// łącznie z końcem strumienia.
+#[derive(Debug)]
+pub struct StreamMatches<R> {
+    reader: R,
+    engine: Engine,
+    buf: Vec<u8>,
+    buf_pos: usize,
+    buf_len: usize,
+    pos: u64, // pozycja bezwzględna bajtu buf[buf_pos]
+    done: bool,
+}
+
+impl<R: Read> StreamMatches<R> {
+    /// Przygotowuje wyszukiwanie wzorca `pattern` w strumieniu `reader`.
+    pub fn new(reader: R, pattern: &str, algorithm: StreamAlgorithm) -> Self {
+        let pattern = pattern.as_bytes();
+        let engine = if pattern.is_empty() {
+            Engine::Empty
+        } else {
+            match algorithm {
+                StreamAlgorithm::Automaton => Engine::Automaton {
+                    table: build_byte_table(pattern),
+                    m: pattern.len(),
+                    state: 0,
+                },
+                StreamAlgorithm::RabinKarp => Engine::RabinKarp {
+                    rk: RabinKarp::from_bytes(pattern),
+                    hash_text: 0,
+                    window: vec![0; pattern.len()],
+                },
+            }
+        };
+        StreamMatches {
+            reader,
+            engine,
+            buf: vec![0; DEFAULT_CHUNK_SIZE],
+            buf_pos: 0,
+            buf_len: 0,
+            pos: 0,
+            done: false,
+        }
+    }
+
+    /// Zmienia rozmiar porcji wczytywanej ze strumienia (co najmniej 1 bajt).
+    ///
+    /// Można ją zmienić także w trakcie iteracji: wczytane, a jeszcze
+    /// nieprzetworzone bajty przenoszone są do nowego bufora (który w razie
+    /// potrzeby jest większy niż `chunk_size`).
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        let unread = &self.buf[self.buf_pos..self.buf_len];
+        let mut buf = vec![0; chunk_size.max(1).max(unread.len())];
+        buf[..unread.len()].copy_from_slice(unread);
+        self.buf_len = unread.len();
+        self.buf_pos = 0;
+        self.buf = buf;
+        self
+    }
+
+    /// Liczba bajtów strumienia przetworzonych do tej pory.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Zwraca czytany strumień.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for StreamMatches<R> {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<io::Result<u64>> {
+        if self.done {
+            return None;
+        }
+        loop {
+            while self.buf_pos < self.buf_len {
+                let b = self.buf[self.buf_pos];
+                let pos = self.pos;
+                self.buf_pos += 1;
+                self.pos += 1;
+                if let Some(start) = self.engine.step(b, pos) {
+                    return Some(Ok(start));
+                }
+            }
+            // Bieżąca porcja przetworzona – wczytujemy kolejną.
+            match self.reader.read(&mut self.buf) {
+                Ok(0) => {
+                    self.done = true;
+                    // Pusty wzorzec występuje też na końcu strumienia.
+                    return matches!(self.engine, Engine::Empty).then_some(Ok(self.pos));
+                }
+                Ok(n) => {
+                    self.buf_pos = 0;
+                    self.buf_len = n;
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Skrót dla [`StreamMatches::new`] z algorytmem [`StreamAlgorithm::Automaton`].
+pub fn stream_find_iter<R: Read>(reader: R, pattern: &str) -> StreamMatches<R> {
+    StreamMatches::new(reader, pattern, StreamAlgorithm::Automaton)
+}
@@ -0,0 +1,135 @@
+use std::ops::Range;
+
+/// Tablica sufiksowa: indeksy początków niepustych sufiksów `text`
+/// w porządku leksykograficznym.
+///
+/// Budowana metodą podwajania prefiksów: po rundzie `k` sufiksy są posortowane
+/// według pierwszych `2^k` symboli, a rangę pary (ranga `i`, ranga `i + 2^k`)
+/// wyznacza już posortowanie par. Wystarcza O(log n) rund po O(n log n)
+/// (sortowanie), czyli O(n log² n) łącznie. Działa dla dowolnego alfabetu
+/// z porządkiem, np. bajtów czy [`Nucleotide`](crate::dna::Nucleotide).
+pub fn suffix_array<T: Ord>(text: &[T]) -> Vec<usize> {
+    let n = text.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    sa.sort_by(|&a, &b| text[a].cmp(&text[b]));
+
+    // Rangi pierwszych symboli; równe symbole dostają równe rangi.
+    let mut rank = vec![0; n];
+    for w in 1..n {
+        let same = text[sa[w - 1]] == text[sa[w]];
+        rank[sa[w]] = rank[sa[w - 1]] + usize::from(!same);
+    }
+
+    let mut k = 1;
+    let mut tmp = vec![0; n];
+    while n > 0 && rank[sa[n - 1]] < n - 1 {
+        // Klucz sufiksu i po 2k symbolach; brak drugiej połowy jest najmniejszy.
+        let key = |i: usize| (rank[i], if i + k < n { rank[i + k] + 1 } else { 0 });
+        sa.sort_by_key(|&i| key(i));
+        tmp[sa[0]] = 0;
+        for w in 1..n {
+            tmp[sa[w]] = tmp[sa[w - 1]] + usize::from(key(sa[w - 1]) != key(sa[w]));
+        }
+        std::mem::swap(&mut rank, &mut tmp);
+        k *= 2;
+    }
+    sa
+}
+
+/// Tablica LCP algorytmem Kasaia: `lcp[w]` to długość najdłuższego wspólnego
+/// prefiksu sufiksów `sa[w - 1]` i `sa[w]` (`lcp[0] = 0`).
+///
+/// Sufiksy przeglądane są w kolejności w tekście; skoro sufiks `i + 1` to
+/// sufiks `i` bez pierwszego symbolu, jego LCP z poprzednikiem jest co
+/// najwyżej o 1 mniejsze, więc licznik zmniejsza się o 1 na krok i cały
+/// algorytm działa w czasie O(n).
+pub fn lcp_array<T: PartialEq>(text: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut rank = vec![0; n];
+    for (w, &i) in sa.iter().enumerate() {
+        rank[i] = w;
+    }
+    let mut lcp = vec![0; n];
+    let mut h = 0;
+    for i in 0..n {
+        if rank[i] == 0 {
+            h = 0;
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && text[i + h] == text[j + h] {
+            h += 1;
+        }
+        lcp[rank[i]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+/// Indeks pełnotekstowy: tekst wraz z tablicą sufiksową i tablicą LCP.
+///
+/// Wystąpienia wzorca długości `m` to spójny przedział tablicy sufiksowej,
+/// znajdowany dwoma wyszukiwaniami binarnymi w czasie O(m log n).
+#[derive(Debug, Clone)]
+pub struct SuffixArray<T> {
+    text: Vec<T>,
+    sa: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<T: Ord + Clone> SuffixArray<T> {
+    /// Buduje indeks dla kopii `text`.
+    pub fn new(text: &[T]) -> Self {
+        let sa = suffix_array(text);
+        let lcp = lcp_array(text, &sa);
+        SuffixArray {
+            text: text.to_vec(),
+            sa,
+            lcp,
+        }
+    }
+
+    /// Zaindeksowany tekst.
+    pub fn text(&self) -> &[T] {
+        &self.text
+    }
+
+    /// Tablica sufiksowa.
+    pub fn suffix_array(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// Tablica LCP (patrz [`lcp_array`]).
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Przedział tablicy sufiksowej z sufiksami zaczynającymi się od `pattern`.
+    pub fn range(&self, pattern: &[T]) -> Range<usize> {
+        let m = pattern.len();
+        let prefix = |s: usize| &self.text[s..(s + m).min(self.text.len())];
+        let lo = self.sa.partition_point(|&s| prefix(s) < pattern);
+        let hi = lo + self.sa[lo..].partition_point(|&s| prefix(s) <= pattern);
+        lo..hi
+    }
+
+    /// Liczba (także nakładających się) wystąpień `pattern`.
+    /// Pusty wzorzec występuje `n + 1` razy.
+    pub fn count(&self, pattern: &[T]) -> usize {
+        if pattern.is_empty() {
+            return self.text.len() + 1;
+        }
+        self.range(pattern).len()
+    }
+
+    /// Posortowane pozycje wszystkich (także nakładających się) wystąpień
+    /// `pattern`. Pusty wzorzec występuje na każdej pozycji 0..=n.
+    pub fn find_all(&self, pattern: &[T]) -> Vec<usize> {
+        if pattern.is_empty() {
+            return (0..=self.text.len()).collect();
+        }
+        let mut wynik = self.sa[self.range(pattern)].to_vec();
+        wynik.sort_unstable();
+        wynik
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use super::repeats::CommonSubstring;
+
+#[derive(Debug, Clone)]
+struct State {
+    /// Długość (w znakach) najdłuższego słowa w klasie stanu.
+    len: usize,
+    /// Link sufiksowy; `None` tylko dla stanu początkowego.
+    link: Option<usize>,
+    next: HashMap<char, usize>,
+    /// Koniec (w bajtach) pierwszego wystąpienia słów z klasy stanu.
+    first_end: usize,
+}
+
+/// Automat sufiksowy (DAWG) tekstu: najmniejszy DFA akceptujący wszystkie
+/// jego podsłowa. Stan odpowiada klasie podsłów o tym samym zbiorze pozycji
+/// końcowych, więc stanów jest co najwyżej `2n - 1`, a przejść `3n - 4`.
+///
+/// Budowa jest przyrostowa ([`push`](Self::push)): dopisanie znaku kosztuje
+/// zamortyzowane O(1) operacji na tablicach haszujących, jak w
+/// [`build_prefix_automaton`](super::build_prefix_automaton).
+#[derive(Debug, Clone)]
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+    last: usize,
+    text_len: usize,
+}
+
+impl Default for SuffixAutomaton {
+    fn default() -> Self {
+        SuffixAutomaton {
+            states: vec![State {
+                len: 0,
+                link: None,
+                next: HashMap::new(),
+                first_end: 0,
+            }],
+            last: 0,
+            text_len: 0,
+        }
+    }
+}
+
+impl SuffixAutomaton {
+    /// Buduje automat dla całego tekstu.
+    pub fn new(text: &str) -> Self {
+        let mut sam = Self::default();
+        text.chars().for_each(|c| sam.push(c));
+        sam
+    }
+
+    /// Dopisuje znak `c` na koniec tekstu.
+    pub fn push(&mut self, c: char) {
+        self.text_len += c.len_utf8();
+        let cur = self.states.len();
+        self.states.push(State {
+            len: self.states[self.last].len + 1,
+            link: None,
+            next: HashMap::new(),
+            first_end: self.text_len,
+        });
+
+        // Dodajemy przejście po `c` do wszystkich sufiksów, które go nie mają.
+        let mut p = Some(self.last);
+        while let Some(v) = p {
+            if self.states[v].next.contains_key(&c) {
+                break;
+            }
+            self.states[v].next.insert(c, cur);
+            p = self.states[v].link;
+        }
+
+        self.states[cur].link = Some(match p {
+            None => 0,
+            Some(v) => {
+                let q = self.states[v].next[&c];
+                if self.states[v].len + 1 == self.states[q].len {
+                    q
+                } else {
+                    // Klasa `q` się rozpada: krótsze słowa przenosimy do klonu.
+                    let clone = self.states.len();
+                    self.states.push(State {
+                        len: self.states[v].len + 1,
+                        ..self.states[q].clone()
+                    });
+                    let mut p = Some(v);
+                    while let Some(u) = p {
+                        if self.states[u].next.get(&c) != Some(&q) {
+                            break;
+                        }
+                        self.states[u].next.insert(c, clone);
+                        p = self.states[u].link;
+                    }
+                    self.states[q].link = Some(clone);
+                    clone
+                }
+            }
+        });
+        self.last = cur;
+    }
+
+    /// Liczba stanów automatu.
+    pub fn states(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Stan osiągany po wczytaniu `pattern` od stanu początkowego.
+    fn walk(&self, pattern: &str) -> Option<usize> {
+        pattern
+            .chars()
+            .try_fold(0, |v, c| self.states[v].next.get(&c).copied())
+    }
+
+    /// Czy `pattern` jest podsłowem tekstu; czas O(m).
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.walk(pattern).is_some()
+    }
+
+    /// Pozycja (w bajtach) pierwszego wystąpienia `pattern` w tekście; O(m).
+    /// Pusty wzorzec występuje na pozycji 0.
+    pub fn first_occurrence(&self, pattern: &str) -> Option<usize> {
+        let v = self.walk(pattern)?;
+        Some(self.states[v].first_end - pattern.len())
+    }
+
+    /// Liczba różnych niepustych podsłów tekstu: suma `len(v) - len(link(v))`
+    /// po stanach, bo stan `v` reprezentuje dokładnie tyle słów.
+    pub fn distinct_substrings(&self) -> usize {
+        self.states
+            .iter()
+            .filter_map(|s| s.link.map(|l| s.len - self.states[l].len))
+            .sum()
+    }
+
+    /// Najdłuższe wspólne podsłowo tekstu i `other` w czasie O(|other|).
+    ///
+    /// Czyta `other`, utrzymując najdłuższy sufiks przeczytanej części będący
+    /// podsłowem tekstu; przy braku przejścia skraca go linkami sufiksowymi.
+    /// Spośród najdłuższych wybiera słowo kończące się najwcześniej w `other`;
+    /// `positions` to pierwsze wystąpienia w tekście i w `other`. Zwraca
+    /// `None`, jeśli nie ma wspólnego znaku.
+    pub fn longest_common_substring(&self, other: &str) -> Option<CommonSubstring> {
+        let (mut v, mut l) = (0, 0);
+        // (długość w znakach, początek i koniec w `other` w bajtach, stan)
+        let mut best: Option<(usize, usize, usize, usize)> = None;
+        let offsets: Vec<usize> = other.char_indices().map(|(i, _)| i).collect();
+        for (j, c) in other.chars().enumerate() {
+            while v != 0 && !self.states[v].next.contains_key(&c) {
+                v = self.states[v].link.unwrap_or(0);
+                l = self.states[v].len;
+            }
+            match self.states[v].next.get(&c) {
+                Some(&u) => {
+                    v = u;
+                    l += 1;
+                }
+                None => continue,
+            }
+            if best.is_none_or(|(bl, ..)| l > bl) {
+                let end = offsets[j] + c.len_utf8();
+                best = Some((l, offsets[j + 1 - l], end, v));
+            }
+        }
+        let (_, start, end, v) = best?;
+        Some(CommonSubstring {
+            substring: other[start..end].to_string(),
+            positions: vec![self.states[v].first_end - (end - start), start],
+        })
+    }
+}
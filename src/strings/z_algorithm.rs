@@ -0,0 +1,53 @@
+/// Tablica Z ciągu `s`: `z[i]` to długość najdłuższego wspólnego prefiksu
+/// `s` i `s[i..]`. Przyjmujemy `z[0] = n`.
+///
+/// Algorytm utrzymuje najdalej sięgający przedział `[l, r)` zgodny z prefiksem
+/// (tzw. Z-box); wewnątrz niego `z[i]` startuje od `z[i - l]`, a każde
+/// porównanie znaków poza nim przesuwa `r`, więc całość działa w czasie O(n).
+pub fn z_array<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        let mut k = if i < r { z[i - l].min(r - i) } else { 0 };
+        while i + k < n && s[k] == s[i + k] {
+            k += 1;
+        }
+        z[i] = k;
+        if i + k > r {
+            l = i;
+            r = i + k;
+        }
+    }
+    z
+}
+
+/// Wyszukuje wszystkie (także nakładające się) wystąpienia `pattern` w `text`
+/// za pomocą tablicy Z ciągu `pattern + sep + text`, gdzie separator jest
+/// różny od wszystkich symboli (`None` wśród `Some(&symbol)`). Wzorzec
+/// występuje na pozycji `i` tekstu, gdy odpowiadająca jej wartość Z jest
+/// równa `m`. Zwraca indeksy początków wystąpień, czas O(n + m).
+///
+/// Pusty wzorzec występuje na każdej pozycji 0..=text.len().
+pub fn z_search<T: PartialEq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let joined: Vec<Option<&T>> = pattern
+        .iter()
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain(text.iter().map(Some))
+        .collect();
+    z_array(&joined)[m + 1..]
+        .iter()
+        .enumerate()
+        .filter(|&(_, &z)| z == m)
+        .map(|(i, _)| i)
+        .collect()
+}
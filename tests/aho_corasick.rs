@@ -0,0 +1,159 @@
+use algorithmics::strings::{AhoCorasick, Match, MatchKind};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const KINDS: [MatchKind; 3] = [
+    MatchKind::Standard,
+    MatchKind::LeftmostFirst,
+    MatchKind::LeftmostLongest,
+];
+
+/// Wszystkie wystąpienia wszystkich wzorców, w kolejności zwracanej przez
+/// `MatchKind::Standard`: po końcu, potem od najdłuższego, potem po numerze.
+fn brute_force_all(haystack: &[u8], patterns: &[&str], starts: &[usize]) -> Vec<Match> {
+    let mut wynik: Vec<Match> = starts
+        .iter()
+        .flat_map(|&start| {
+            patterns
+                .iter()
+                .enumerate()
+                .filter(move |(_, p)| haystack[start..].starts_with(p.as_bytes()))
+                .map(move |(pattern, p)| Match {
+                    pattern,
+                    start,
+                    end: start + p.len(),
+                })
+        })
+        .collect();
+    wynik.sort_by_key(|m| (m.end, m.start, m.pattern));
+    wynik
+}
+
+/// Wybór rozłącznych wystąpień od lewej spośród wszystkich wystąpień.
+fn brute_force_leftmost(all: &[Match], kind: MatchKind) -> Vec<Match> {
+    let mut wynik = Vec::new();
+    let mut cursor = 0;
+    while let Some(start) = all.iter().map(|m| m.start).filter(|&s| s >= cursor).min() {
+        let at_start = all.iter().filter(|m| m.start == start);
+        let best = *match kind {
+            MatchKind::LeftmostLongest => {
+                at_start.min_by_key(|m| (std::cmp::Reverse(m.end), m.pattern))
+            }
+            _ => at_start.min_by_key(|m| m.pattern),
+        }
+        .unwrap();
+        cursor = if best.start == best.end {
+            best.end + 1
+        } else {
+            best.end
+        };
+        wynik.push(best);
+    }
+    wynik
+}
+
+fn expected(haystack: &[u8], patterns: &[&str], starts: &[usize], kind: MatchKind) -> Vec<Match> {
+    let all = brute_force_all(haystack, patterns, starts);
+    match kind {
+        MatchKind::Standard => all,
+        _ => brute_force_leftmost(&all, kind),
+    }
+}
+
+fn check_str(text: &str, patterns: &[&str]) {
+    let starts: Vec<usize> = (0..=text.len())
+        .filter(|&i| text.is_char_boundary(i))
+        .collect();
+    for kind in KINDS {
+        let want = expected(text.as_bytes(), patterns, &starts, kind);
+        let ac = AhoCorasick::new(patterns, kind);
+        let got: Vec<Match> = ac.find_iter(text).collect();
+        assert_eq!(got, want, "{kind:?} {patterns:?} w {text:?}");
+        let dfa = ac.compile_dfa();
+        let got: Vec<Match> = dfa.find_iter(text).collect();
+        assert_eq!(got, want, "DFA {kind:?} {patterns:?} w {text:?}");
+    }
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[&str], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn overlapping_patterns() {
+    let patterns = ["ab", "abc", "b"];
+    let ac = AhoCorasick::new(&patterns, MatchKind::LeftmostFirst);
+    let got: Vec<(usize, usize)> = ac
+        .find_iter("abcab")
+        .map(|m| (m.pattern, m.start))
+        .collect();
+    assert_eq!(got, vec![(0, 0), (0, 3)]);
+    let ac = AhoCorasick::new(&patterns, MatchKind::LeftmostLongest);
+    let got: Vec<(usize, usize)> = ac
+        .find_iter("abcab")
+        .map(|m| (m.pattern, m.start))
+        .collect();
+    assert_eq!(got, vec![(1, 0), (0, 3)]);
+
+    for text in ["abcab", "abababc", "bbb", "xabcbx", ""] {
+        check_str(text, &patterns);
+    }
+}
+
+#[test]
+fn empty_and_duplicate_patterns() {
+    check_str("aąb", &["", "ab", "b"]);
+    check_str("abab", &["ab", "", "ab"]);
+    check_str("", &[""]);
+}
+
+#[test]
+fn random_patterns_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let alphabet = ["a", "b", "ą"];
+    for _ in 0..300 {
+        let count = rng.random_range(1..=5);
+        let patterns: Vec<String> = (0..count)
+            .map(|_| random_word(&mut rng, &alphabet, 4))
+            .collect();
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let text = random_word(&mut rng, &alphabet, 30);
+        check_str(&text, &patterns);
+    }
+}
+
+#[test]
+fn invalid_utf8_bytes_do_not_match() {
+    let mut rng = StdRng::seed_from_u64(5);
+    // Samotny pierwszy bajt "ą" (0xC4) i 0xFF to niepoprawne sekwencje UTF-8.
+    let pieces: [&[u8]; 5] = [b"a", b"b", "ą".as_bytes(), &[0xC4], &[0xFF]];
+    for _ in 0..300 {
+        let count = rng.random_range(1..=4);
+        let patterns: Vec<String> = (0..count)
+            .map(|_| {
+                let mut p = random_word(&mut rng, &["a", "b", "ą"], 3);
+                if p.is_empty() {
+                    p.push('a');
+                }
+                p
+            })
+            .collect();
+        let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let len = rng.random_range(0..=25);
+        let haystack: Vec<u8> = (0..len)
+            .flat_map(|_| pieces[rng.random_range(0..pieces.len())].iter().copied())
+            .collect();
+        let starts: Vec<usize> = (0..=haystack.len()).collect();
+        for kind in KINDS {
+            let want = expected(&haystack, &patterns, &starts, kind);
+            let ac = AhoCorasick::new(&patterns, kind);
+            let got: Vec<Match> = ac.find_bytes_iter(&haystack).collect();
+            assert_eq!(got, want, "{kind:?} {patterns:?} w {haystack:?}");
+            let got: Vec<Match> = ac.compile_dfa().find_bytes_iter(&haystack).collect();
+            assert_eq!(got, want, "DFA {kind:?} {patterns:?} w {haystack:?}");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use algorithmics::strings::{
+    ApproxMatch, edit_search, edit_search_str, hamming_search, hamming_search_str,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Odległość edycyjna (Levenshteina) pełnym programowaniem dynamicznym.
+fn levenshtein(a: &[u8], b: &[u8]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, y) in b.iter().enumerate() {
+            cur[j + 1] = (prev[j] + usize::from(x != y))
+                .min(prev[j + 1] + 1)
+                .min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Dla każdego końca: najmniejsza odległość wzorca od fragmentu tekstu
+/// kończącego się w tym miejscu, liczona dla wszystkich początków.
+fn brute_force_edit(text: &[u8], pattern: &[u8], k: usize) -> Vec<ApproxMatch> {
+    (0..=text.len())
+        .filter_map(|end| {
+            let distance = (0..=end)
+                .map(|start| levenshtein(pattern, &text[start..end]))
+                .min()
+                .unwrap();
+            (distance <= k).then_some(ApproxMatch { end, distance })
+        })
+        .collect()
+}
+
+fn brute_force_hamming(text: &[u8], pattern: &[u8], k: usize) -> Vec<ApproxMatch> {
+    (pattern.len()..=text.len())
+        .filter_map(|end| {
+            let window = &text[end - pattern.len()..end];
+            let distance = window.iter().zip(pattern).filter(|(a, b)| a != b).count();
+            (distance <= k).then_some(ApproxMatch { end, distance })
+        })
+        .collect()
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[u8], max_len: usize) -> Vec<u8> {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_matches() {
+    let hits = hamming_search(b"abcabd", b"abd", 1);
+    assert_eq!(
+        hits,
+        [
+            ApproxMatch {
+                end: 3,
+                distance: 1
+            },
+            ApproxMatch {
+                end: 6,
+                distance: 0
+            }
+        ]
+    );
+    let ends: Vec<usize> = edit_search(b"surgery", b"survey", 2)
+        .iter()
+        .map(|hit| hit.end)
+        .collect();
+    assert_eq!(ends, [5, 6, 7]);
+    assert_eq!(hamming_search(b"ab", b"abc", 3), []);
+}
+
+#[test]
+fn str_variants_report_byte_offsets() {
+    let hits = hamming_search_str("żółw żółć", "żółw", 1);
+    assert_eq!(hits.iter().map(|h| h.end).collect::<Vec<_>>(), [7, 16]);
+    assert_eq!(hits[1].distance, 1);
+    let hits = edit_search_str("ąbą", "ą", 0);
+    assert_eq!(hits.iter().map(|h| h.end).collect::<Vec<_>>(), [2, 5]);
+}
+
+#[test]
+fn random_texts_match_full_dynamic_programming() {
+    let mut rng = StdRng::seed_from_u64(21);
+    for _ in 0..500 {
+        let text = random_word(&mut rng, b"abc", 20);
+        let pattern = random_word(&mut rng, b"abc", 6);
+        let k = rng.random_range(0..4);
+        assert_eq!(
+            edit_search(&text, &pattern, k),
+            brute_force_edit(&text, &pattern, k),
+            "k = {k}, {pattern:?} w {text:?}"
+        );
+        assert_eq!(
+            hamming_search(&text, &pattern, k),
+            brute_force_hamming(&text, &pattern, k),
+            "k = {k}, {pattern:?} w {text:?}"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use algorithmics::strings::{overlap_matrix, shortest_common_superstring};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Długość najkrótszego wspólnego nadsłowa: najlepsza kolejność sklejania
+/// fragmentów nie zawartych w innych, z maksymalnymi zakładkami.
+fn optimal_length(fragments: &[String]) -> usize {
+    let mut kept: Vec<&String> = Vec::new();
+    for (i, f) in fragments.iter().enumerate() {
+        let contained = fragments
+            .iter()
+            .enumerate()
+            .any(|(j, g)| j != i && g.contains(f.as_str()) && (g.len() > f.len() || j < i));
+        if !contained {
+            kept.push(f);
+        }
+    }
+    let mut best = usize::MAX;
+    permutations(&mut (0..kept.len()).collect(), 0, &mut |order| {
+        let mut len = kept[order[0]].len();
+        for w in order.windows(2) {
+            let (a, b) = (kept[w[0]].as_bytes(), kept[w[1]].as_bytes());
+            let shared = (0..=a.len().min(b.len()))
+                .rev()
+                .find(|&k| a[a.len() - k..] == b[..k])
+                .unwrap();
+            len += b.len() - shared;
+        }
+        best = best.min(len);
+    });
+    best
+}
+
+fn permutations(items: &mut Vec<usize>, k: usize, visit: &mut impl FnMut(&[usize])) {
+    if k == items.len() {
+        visit(items);
+        return;
+    }
+    for i in k..items.len() {
+        items.swap(k, i);
+        permutations(items, k + 1, visit);
+        items.swap(k, i);
+    }
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[char], len: usize) -> String {
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_assembly() {
+    let fragments = ["ATTAGACCTG", "CCTGCCGGAA", "AGACCTGCCG", "GCCGGAATAC"];
+    let result = shortest_common_superstring(&fragments);
+    assert_eq!(result.superstring, "ATTAGACCTGCCGGAATAC");
+    assert_eq!(result.order, [0, 2, 1, 3]);
+    assert_eq!(result.positions, [0, 6, 3, 9]);
+
+    let m = overlap_matrix(&["abc", "bcd", "cde"]);
+    assert_eq!(m, [[0, 2, 1], [0, 0, 2], [0, 0, 0]]);
+    // Fragmenty zawarte w innych nie trafiają do `order`.
+    let result = shortest_common_superstring(&["żółw", "ół", "żółw"]);
+    assert_eq!(result.superstring, "żółw");
+    assert_eq!(result.order, [0]);
+    assert_eq!(result.positions, [0, 2, 0]);
+    assert_eq!(shortest_common_superstring::<&str>(&[]).superstring, "");
+}
+
+#[test]
+fn random_fragments_are_placed_correctly() {
+    let mut rng = StdRng::seed_from_u64(16);
+    let alphabet = ['a', 'b', 'ą'];
+    for _ in 0..300 {
+        let genome = random_word(&mut rng, &alphabet, 20);
+        let chars: Vec<char> = genome.chars().collect();
+        let fragments: Vec<String> = (0..rng.random_range(1..=6))
+            .map(|_| {
+                let start = rng.random_range(0..chars.len());
+                let len = rng.random_range(1..=6.min(chars.len() - start));
+                chars[start..start + len].iter().collect()
+            })
+            .collect();
+        let result = shortest_common_superstring(&fragments);
+        for (i, f) in fragments.iter().enumerate() {
+            let at = result.positions[i];
+            assert!(
+                result.superstring[at..].starts_with(f.as_str()),
+                "{f:?} w {:?}",
+                result.superstring
+            );
+        }
+        let mut sorted = result.order.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), result.order.len());
+        // Heurystyka zachłanna nie musi być optymalna, ale nie gorsza niż
+        // sklejenie bez zakładek i nie lepsza niż optimum.
+        let total: usize = result.order.iter().map(|&i| fragments[i].len()).sum();
+        assert!(result.superstring.len() <= total);
+        assert!(result.superstring.len() >= optimal_length(&fragments));
+
+        let m = overlap_matrix(&fragments.iter().map(|f| f.as_bytes()).collect::<Vec<_>>());
+        for (i, a) in fragments.iter().enumerate() {
+            for (j, b) in fragments.iter().enumerate() {
+                let (a, b) = (a.as_bytes(), b.as_bytes());
+                let want = if i == j {
+                    0
+                } else {
+                    (0..=a.len().min(b.len()))
+                        .rev()
+                        .find(|&k| a[a.len() - k..] == b[..k])
+                        .unwrap()
+                };
+                assert_eq!(m[i][j], want);
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use algorithmics::strings::{Matcher, PrefixAutomaton, Representation, find_pattern};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Wszystkie wystąpienia `pattern` w `haystack`, porównaniem na każdej pozycji
+/// ze zbioru `starts`.
+fn brute_force(haystack: &[u8], pattern: &str, starts: &[usize]) -> Vec<usize> {
+    starts
+        .iter()
+        .copied()
+        .filter(|&i| haystack[i..].starts_with(pattern.as_bytes()))
+        .collect()
+}
+
+/// Zachłanny wybór rozłącznych wystąpień od lewej.
+fn non_overlapping(all: &[usize], pattern: &str) -> Vec<usize> {
+    let mut wynik = Vec::new();
+    for &pos in all {
+        if wynik.last().is_none_or(|&last| pos >= last + pattern.len()) {
+            wynik.push(pos);
+        }
+    }
+    wynik
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[&str], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn overlapping_and_non_overlapping_modes() {
+    let automaton = PrefixAutomaton::new("aa");
+    assert_eq!(automaton.find_iter("aaaa").collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(
+        automaton
+            .find_non_overlapping_iter("aaaa")
+            .collect::<Vec<_>>(),
+        [0, 2]
+    );
+    let automaton = PrefixAutomaton::new("ąą");
+    assert_eq!(automaton.find_iter("ąąą").collect::<Vec<_>>(), [0, 2]);
+    assert_eq!(automaton.count("xąąąą"), 3);
+    assert_eq!(automaton.find_first("xąąąą"), Some(1));
+    assert!(!automaton.is_match("ąaą"));
+    assert!(find_pattern("abaąb", "aą"));
+    assert!(!find_pattern("abaąb", "ąa"));
+}
+
+#[test]
+fn random_str_matches_brute_force() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let alphabet = ["a", "b", "ą"];
+    for _ in 0..500 {
+        let pattern = random_word(&mut rng, &alphabet, 4);
+        let text = random_word(&mut rng, &alphabet, 30);
+        let starts: Vec<usize> = (0..=text.len())
+            .filter(|&i| text.is_char_boundary(i))
+            .collect();
+        let want = brute_force(text.as_bytes(), &pattern, &starts);
+        let automaton = PrefixAutomaton::new(&pattern);
+        assert_eq!(
+            automaton.find_iter(&text).collect::<Vec<_>>(),
+            want,
+            "{pattern:?} w {text:?}"
+        );
+        assert_eq!(
+            automaton
+                .find_non_overlapping_iter(&text)
+                .collect::<Vec<_>>(),
+            non_overlapping(&want, &pattern),
+            "{pattern:?} w {text:?}"
+        );
+    }
+}
+
+#[test]
+fn invalid_utf8_bytes_do_not_match() {
+    let mut rng = StdRng::seed_from_u64(3);
+    // Samotny pierwszy bajt "ą" (0xC4) i 0xFF to niepoprawne sekwencje UTF-8.
+    let pieces: [&[u8]; 5] = [b"a", b"b", "ą".as_bytes(), &[0xC4], &[0xFF]];
+    for _ in 0..500 {
+        let mut pattern = random_word(&mut rng, &["a", "b", "ą"], 3);
+        if pattern.is_empty() {
+            pattern.push('ą');
+        }
+        let len = rng.random_range(0..=25);
+        let haystack: Vec<u8> = (0..len)
+            .flat_map(|_| pieces[rng.random_range(0..pieces.len())].iter().copied())
+            .collect();
+        let starts: Vec<usize> = (0..=haystack.len()).collect();
+        let want = brute_force(&haystack, &pattern, &starts);
+        let automaton = PrefixAutomaton::new(&pattern);
+        assert_eq!(
+            automaton.find_iter(&haystack).collect::<Vec<_>>(),
+            want,
+            "{pattern:?} w {haystack:?}"
+        );
+        assert_eq!(
+            automaton
+                .find_non_overlapping_iter(&haystack)
+                .collect::<Vec<_>>(),
+            non_overlapping(&want, &pattern),
+            "{pattern:?} w {haystack:?}"
+        );
+    }
+}
+
+#[test]
+fn empty_pattern_matches_every_char_boundary() {
+    let automaton = PrefixAutomaton::new("");
+    assert_eq!(automaton.find_iter("aąb").collect::<Vec<_>>(), [0, 1, 3, 4]);
+    assert_eq!(
+        automaton
+            .find_non_overlapping_iter("aąb")
+            .collect::<Vec<_>>(),
+        [0, 1, 3, 4]
+    );
+    // Niepoprawny bajt liczy się jako jeden znak.
+    assert_eq!(
+        automaton
+            .find_iter(&[b'a', 0xFF, 0xC4][..])
+            .collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+    assert_eq!(automaton.find_iter("").collect::<Vec<_>>(), [0]);
+}
+
+const REPRESENTATIONS: [Representation; 3] = [
+    Representation::HashMap,
+    Representation::ByteTable,
+    Representation::ClassTable,
+];
+
+#[test]
+fn representations_give_identical_matches() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let pieces: [&[u8]; 6] = [b"a", b"b", "ą".as_bytes(), "ż".as_bytes(), &[0xC4], &[0xFF]];
+    for _ in 0..500 {
+        let pattern = random_word(&mut rng, &["a", "b", "ą", "ż"], 4);
+        let len = rng.random_range(0..=30);
+        let haystack: Vec<u8> = (0..len)
+            .flat_map(|_| pieces[rng.random_range(0..pieces.len())].iter().copied())
+            .collect();
+        let text = String::from_utf8_lossy(&haystack).into_owned();
+        let reference = PrefixAutomaton::new(&pattern);
+        for representation in REPRESENTATIONS {
+            let automaton = PrefixAutomaton::with_representation(&pattern, representation);
+            assert_eq!(automaton.representation(), representation);
+            for h in [&haystack[..], text.as_bytes()] {
+                assert_eq!(
+                    automaton.find_iter(h).collect::<Vec<_>>(),
+                    reference.find_iter(h).collect::<Vec<_>>(),
+                    "{representation:?} {pattern:?} w {h:?}"
+                );
+                assert_eq!(
+                    automaton.find_non_overlapping_iter(h).collect::<Vec<_>>(),
+                    reference.find_non_overlapping_iter(h).collect::<Vec<_>>(),
+                    "{representation:?} {pattern:?} w {h:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use algorithmics::strings::{BoyerMoore, Horspool, Matcher};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Wszystkie wystąpienia `pattern` w `haystack`, porównaniem na każdej pozycji
+/// bajtowej. Pusty wzorzec sprawdzany jest tylko na poprawnym UTF-8, gdzie
+/// występuje na każdej granicy znaku.
+fn brute_force(haystack: &[u8], pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        let text = std::str::from_utf8(haystack).unwrap();
+        return (0..=text.len())
+            .filter(|&i| text.is_char_boundary(i))
+            .collect();
+    }
+    (0..=haystack.len())
+        .filter(|&i| haystack[i..].starts_with(pattern.as_bytes()))
+        .collect()
+}
+
+fn check(haystack: &[u8], pattern: &str) {
+    let want = brute_force(haystack, pattern);
+    let bm = BoyerMoore::new(pattern);
+    assert_eq!(
+        bm.find_iter(haystack).collect::<Vec<_>>(),
+        want,
+        "BM {pattern:?} w {haystack:?}"
+    );
+    let horspool = Horspool::new(pattern);
+    assert_eq!(
+        horspool.find_iter(haystack).collect::<Vec<_>>(),
+        want,
+        "Horspool {pattern:?} w {haystack:?}"
+    );
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[&str], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_matches() {
+    check(b"HERE IS A SIMPLE EXAMPLE", "EXAMPLE");
+    check(b"abababab", "abab");
+    check(b"aaaaaaaa", "aaa");
+    check("żółw, żółwie".as_bytes(), "żółw");
+    check(b"abc", "abcd");
+    check(b"", "a");
+    check("aąb".as_bytes(), "");
+}
+
+#[test]
+fn random_text_matches_brute_force() {
+    let mut rng = StdRng::seed_from_u64(6);
+    // Małe alfabety i okresowe wzorce to najtrudniejsze przypadki dla tablicy
+    // dobrego sufiksu.
+    for alphabet in [&["a", "b"][..], &["a", "b", "c", "ą"][..]] {
+        for _ in 0..1000 {
+            let pattern = random_word(&mut rng, alphabet, 8);
+            let text = random_word(&mut rng, alphabet, 40);
+            check(text.as_bytes(), &pattern);
+        }
+    }
+}
+
+#[test]
+fn invalid_utf8_bytes_do_not_match() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let pieces: [&[u8]; 5] = [b"a", b"b", "ą".as_bytes(), &[0xC4], &[0xFF]];
+    for _ in 0..500 {
+        let pattern = random_word(&mut rng, &["a", "b", "ą"], 4);
+        if pattern.is_empty() {
+            continue;
+        }
+        let len = rng.random_range(0..=25);
+        let haystack: Vec<u8> = (0..len)
+            .flat_map(|_| pieces[rng.random_range(0..pieces.len())].iter().copied())
+            .collect();
+        check(&haystack, &pattern);
+    }
+}
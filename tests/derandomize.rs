@@ -0,0 +1,35 @@
+use algorithmics::Error;
+use algorithmics::derandomize::{cond_exp, derandomized_bits, is_success, random_bits};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn conditional_expectations() {
+    assert_eq!(cond_exp(&[], 3).unwrap(), 0.125);
+    assert_eq!(cond_exp(&[0, 0], 3).unwrap(), 0.5);
+    assert_eq!(cond_exp(&[0, 1], 3).unwrap(), 0.0);
+    assert_eq!(cond_exp(&[0, 0, 0], 3).unwrap(), 1.0);
+    assert!(matches!(
+        cond_exp(&[0, 0], 1),
+        Err(Error::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn derandomized_bits_always_succeed() {
+    for n in 0..20 {
+        let x = derandomized_bits(n);
+        assert_eq!(x.len(), n);
+        assert!(is_success(&x));
+    }
+    assert!(!is_success(&[0, 1, 0]));
+}
+
+#[test]
+fn random_bits_are_reproducible() {
+    let a = random_bits(64, &mut StdRng::seed_from_u64(8));
+    assert_eq!(a, random_bits(64, &mut StdRng::seed_from_u64(8)));
+    assert_eq!(a.len(), 64);
+    assert!(a.iter().all(|&b| b <= 1));
+    assert!(a.contains(&0) && a.contains(&1));
+}
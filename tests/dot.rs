@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use algorithmics::strings::{
+    AhoCorasick, DotOptions, MatchKind, PrefixAutomaton, to_dot, transition_table,
+};
+
+const HIDE: DotOptions = DotOptions {
+    hide_back_edges: true,
+};
+
+#[test]
+fn prefix_automaton_dot() {
+    let automaton = PrefixAutomaton::new("aab");
+    assert_eq!(
+        automaton.to_dot(DotOptions::default()),
+        "digraph automat {
+    rankdir=LR;
+    node [shape=circle];
+    start [shape=point];
+    start -> 0;
+    0 [shape=circle];
+    1 [shape=circle];
+    2 [shape=circle];
+    3 [shape=doublecircle];
+    0 -> 0 [label=\"b\"];
+    0 -> 1 [label=\"a\"];
+    1 -> 0 [label=\"b\"];
+    1 -> 2 [label=\"a\"];
+    2 -> 2 [label=\"a\"];
+    2 -> 3 [label=\"b\"];
+    3 -> 0 [label=\"b\"];
+    3 -> 1 [label=\"a\"];
+}
+"
+    );
+    // Pętla w stanie 0 zostaje, znikają tylko powroty z innych stanów.
+    assert_eq!(
+        automaton.to_dot(HIDE),
+        "digraph automat {
+    rankdir=LR;
+    node [shape=circle];
+    start [shape=point];
+    start -> 0;
+    // pominięto krawędzie prowadzące z powrotem do stanu 0
+    0 [shape=circle];
+    1 [shape=circle];
+    2 [shape=circle];
+    3 [shape=doublecircle];
+    0 -> 0 [label=\"b\"];
+    0 -> 1 [label=\"a\"];
+    1 -> 2 [label=\"a\"];
+    2 -> 2 [label=\"a\"];
+    2 -> 3 [label=\"b\"];
+    3 -> 1 [label=\"a\"];
+}
+"
+    );
+}
+
+#[test]
+fn prefix_automaton_transition_table() {
+    assert_eq!(
+        PrefixAutomaton::new("aab").transition_table(),
+        "     | a b
+→  0 | 1 0
+   1 | 2 0
+   2 | 2 3
+ * 3 | 1 0
+"
+    );
+}
+
+#[test]
+fn aho_corasick_dot() {
+    let ac = AhoCorasick::new(&["he", "she"], MatchKind::Standard);
+    let header = "digraph automat {
+    rankdir=LR;
+    node [shape=circle];
+    start [shape=point];
+    start -> 0;
+";
+    let body = "    0 [shape=circle];
+    1 [shape=circle];
+    2 [shape=doublecircle];
+    3 [shape=circle];
+    4 [shape=circle];
+    5 [shape=doublecircle];
+    0 -> 1 [label=\"h\"];
+    0 -> 3 [label=\"s\"];
+    1 -> 2 [label=\"e\"];
+    3 -> 4 [label=\"h\"];
+    4 -> 5 [label=\"e\"];
+";
+    assert_eq!(
+        ac.to_dot(DotOptions::default()),
+        format!(
+            "{header}{body}    1 -> 0 [style=dashed];
+    2 -> 0 [style=dashed];
+    3 -> 0 [style=dashed];
+    4 -> 1 [style=dashed];
+    5 -> 2 [style=dashed];
+}}
+"
+        )
+    );
+    assert_eq!(
+        ac.to_dot(HIDE),
+        format!(
+            "{header}    // pominięto krawędzie prowadzące z powrotem do stanu 0
+{body}    4 -> 1 [style=dashed];
+    5 -> 2 [style=dashed];
+}}
+"
+        )
+    );
+    // Po kompilacji do DFA krawędzie porażki są już wbudowane w przejścia.
+    let dfa = ac.compile_dfa().to_dot(DotOptions::default());
+    assert!(!dfa.contains("dashed"));
+    assert!(dfa.contains("    4 -> 5 [label=\"e\"];\n"));
+    assert!(dfa.contains("    2 [shape=doublecircle];\n"));
+}
+
+#[test]
+fn escaping() {
+    let transitions = vec![HashMap::from([('"', 1), ('\n', 1)]), HashMap::new()];
+    assert_eq!(
+        to_dot(&transitions, &[1], DotOptions::default()),
+        "digraph automat {
+    rankdir=LR;
+    node [shape=circle];
+    start [shape=point];
+    start -> 0;
+    0 [shape=circle];
+    1 [shape=doublecircle];
+    0 -> 1 [label=\"\\\\n,\\\"\"];
+}
+"
+    );
+    assert_eq!(
+        transition_table(&transitions, &[0, 1]),
+        "     | \\n \"
+→* 0 |  1 1
+ * 1 |  - -
+"
+    );
+}
@@ -0,0 +1,183 @@
+use std::io::ErrorKind;
+
+use algorithmics::dna::parse_sequence;
+use algorithmics::strings::fm_index::MAX_OCC_RATE;
+use algorithmics::strings::{FmIndex, bwt, inverse_bwt};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Długość nagłówka: `FMI1` i pięć liczb `u64`.
+const HEADER: usize = 4 + 5 * 8;
+
+fn random_text(rng: &mut StdRng, alphabet: &[u8], max_len: usize) -> Vec<u8> {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+fn brute_force(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&i| text[i..].starts_with(pattern))
+        .collect()
+}
+
+fn serialized(index: &FmIndex) -> Vec<u8> {
+    let mut buf = Vec::new();
+    index.write_to(&mut buf).unwrap();
+    buf
+}
+
+fn header(n: u64, primary: u64, occ_rate: u64, sa_rate: u64, samples: u64) -> Vec<u8> {
+    let mut buf = b"FMI1".to_vec();
+    for v in [n, primary, occ_rate, sa_rate, samples] {
+        buf.extend(v.to_le_bytes());
+    }
+    buf
+}
+
+#[test]
+fn inverse_bwt_round_trips() {
+    let mut rng = StdRng::seed_from_u64(19);
+    for _ in 0..200 {
+        let text = random_text(&mut rng, b"abc", 50);
+        let (transformed, primary) = bwt(&text);
+        assert_eq!(inverse_bwt(&transformed, primary).unwrap(), text);
+    }
+    assert_eq!(bwt(b"banana"), (b"annbaa".to_vec(), 4));
+    assert!(inverse_bwt(b"ab", 3).is_err());
+}
+
+#[test]
+fn text_count_and_locate_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(20);
+    for _ in 0..100 {
+        let text = random_text(&mut rng, b"ab$", 60);
+        let occ_rate = rng.random_range(1..=8);
+        let sa_rate = rng.random_range(1..=8);
+        let index = FmIndex::with_sampling(&text, occ_rate, sa_rate).unwrap();
+        assert_eq!(index.text(), text);
+        assert_eq!(index.len(), text.len());
+        for _ in 0..10 {
+            let pattern = random_text(&mut rng, b"ab$", 4);
+            let want = brute_force(&text, &pattern);
+            assert_eq!(index.count(&pattern), want.len());
+            assert_eq!(index.locate(&pattern), want);
+        }
+        assert_eq!(index.count(b""), text.len() + 1);
+        assert_eq!(index.locate(b""), (0..=text.len()).collect::<Vec<usize>>());
+        assert_eq!(index.count(b"x"), 0);
+    }
+}
+
+#[test]
+fn nucleotide_index_round_trips_through_bytes() {
+    let seq = parse_sequence("ATGCGATACGCTTGAATGCGA").unwrap();
+    let index = FmIndex::with_sampling(&seq, 4, 3).unwrap();
+    let restored = FmIndex::read_from(serialized(&index).as_slice()).unwrap();
+    assert_eq!(restored, index);
+    let pattern = parse_sequence("ATG").unwrap();
+    assert_eq!(restored.locate(&pattern), vec![0, 15]);
+    assert_eq!(restored.count(&parse_sequence("GC").unwrap()), 3);
+    let text: Vec<u8> = seq.iter().map(|&s| s.into()).collect();
+    assert_eq!(restored.text(), text);
+}
+
+#[test]
+fn truncated_file_is_an_error() {
+    let index = FmIndex::with_sampling(b"mississippi", 2, 2).unwrap();
+    let buf = serialized(&index);
+    for len in 0..buf.len() {
+        let err = FmIndex::read_from(&buf[..len]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof, "długość {len}");
+    }
+}
+
+#[test]
+fn oversized_header_is_rejected_without_allocating() {
+    let err = FmIndex::read_from(header(u64::MAX, 0, 1, 1, 1).as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let mut buf = header(1 << 62, 0, 1, 1, 1);
+    buf.extend(b"abc");
+    let err = FmIndex::read_from(buf.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    for bad in [
+        header(3, 4, 1, 1, 1),
+        header(3, 0, 0, 1, 1),
+        header(3, 0, 1, 0, 1),
+        header(3, 0, 1, 1, 5),
+        header(3, 0, MAX_OCC_RATE as u64 + 1, 1, 1),
+    ] {
+        let err = FmIndex::read_from(bad.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
+
+#[test]
+fn tampered_bwt_is_rejected() {
+    let text = b"abracadabra";
+    let index = FmIndex::with_sampling(text, 4, 4).unwrap();
+    let buf = serialized(&index);
+    let mut tampered = buf.clone();
+    tampered[HEADER] = b'a';
+    tampered[HEADER + 1] = b'a';
+    let err = FmIndex::read_from(tampered.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    // Każda zmiana dwóch bajtów BWT albo indeksu pierwotnego daje błąd
+    // albo spójny indeks, na którym zapytania się kończą.
+    for i in 0..text.len() {
+        for j in 0..text.len() {
+            for (x, y) in [(b'a', b'b'), (b'r', b'c'), (b'd', b'd')] {
+                let mut tampered = buf.clone();
+                tampered[HEADER + i] = x;
+                tampered[HEADER + j] = y;
+                match FmIndex::read_from(tampered.as_slice()) {
+                    Ok(index) => {
+                        assert_eq!(index.text().len(), text.len());
+                        index.locate(b"a");
+                    }
+                    Err(err) => assert_eq!(err.kind(), ErrorKind::InvalidData),
+                }
+            }
+        }
+    }
+    for primary in 0..=text.len() as u64 {
+        let mut tampered = buf.clone();
+        tampered[12..20].copy_from_slice(&primary.to_le_bytes());
+        let result = FmIndex::read_from(tampered.as_slice());
+        if primary == index.bwt().1 as u64 {
+            assert_eq!(result.unwrap(), index);
+        } else {
+            assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+        }
+    }
+}
+
+#[test]
+fn occ_rate_is_bounded() {
+    assert!(FmIndex::with_sampling(b"abc", MAX_OCC_RATE, 1).is_ok());
+    assert!(FmIndex::with_sampling(b"abc", MAX_OCC_RATE + 1, 1).is_err());
+    let text: Vec<u8> = (0..1000).map(|i| b"ab"[i % 7 / 4]).collect();
+    let index = FmIndex::with_sampling(&text, MAX_OCC_RATE, 5).unwrap();
+    assert_eq!(index.text(), text);
+    assert_eq!(index.locate(b"abbba"), brute_force(&text, b"abbba"));
+}
+
+#[test]
+fn marks_beyond_last_row_are_rejected() {
+    let text = b"mississippi";
+    let index = FmIndex::with_sampling(text, 2, 3).unwrap();
+    let buf = serialized(&index);
+    let marks = HEADER + text.len();
+    // Dodatkowy bit za ostatnim (12.) wierszem razem z dodatkową próbką.
+    let mut tampered = buf.clone();
+    tampered[marks + 7] |= 0x80;
+    let samples = u64::from_le_bytes(tampered[36..44].try_into().unwrap());
+    tampered[36..44].copy_from_slice(&(samples + 1).to_le_bytes());
+    tampered.extend(0u64.to_le_bytes());
+    let err = FmIndex::read_from(tampered.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
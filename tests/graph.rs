@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use algorithmics::Error;
+use algorithmics::graph::{Graph, karger_contraction, min_cut};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+fn cycle(n: usize) -> Graph {
+    let mut g = Graph::new(n);
+    for v in 0..n {
+        g.add_edge(v, (v + 1) % n).unwrap();
+    }
+    g
+}
+
+/// Dwie kliki `K_k` połączone jednym mostem `0 – k`.
+fn barbell(k: u32) -> HashMap<u32, Vec<u32>> {
+    let mut adj: HashMap<u32, Vec<u32>> = HashMap::new();
+    for half in [0, k] {
+        for u in half..half + k {
+            for v in half..half + k {
+                if u != v {
+                    adj.entry(u).or_default().push(v);
+                }
+            }
+        }
+    }
+    adj.get_mut(&0).unwrap().push(k);
+    adj.get_mut(&k).unwrap().push(0);
+    adj
+}
+
+#[test]
+fn edges_and_cuts() {
+    let mut g = cycle(4);
+    assert_eq!(g.n(), 4);
+    assert_eq!(g.edges().len(), 4);
+    assert_eq!(g.neighbors(0), &[1, 3]);
+    assert_eq!(g.cut_size(&[true, false, true, false]).unwrap(), 4);
+    assert_eq!(g.cut_size(&[true, true, false, false]).unwrap(), 2);
+    assert!(matches!(
+        g.cut_size(&[true]),
+        Err(Error::InvalidParameter(_))
+    ));
+    assert_eq!(
+        g.add_edge(1, 4),
+        Err(Error::VertexOutOfRange { vertex: 4, n: 4 })
+    );
+}
+
+#[test]
+fn derandomized_cut_has_at_least_half_the_edges() {
+    let mut rng = StdRng::seed_from_u64(2);
+    for n in 2..12 {
+        let mut g = cycle(n);
+        for u in 0..n {
+            for v in u + 2..n {
+                if (u * 7 + v * 3) % 5 == 0 {
+                    g.add_edge(u, v).unwrap();
+                }
+            }
+        }
+        let (side, size) = g.derandomized_cut();
+        assert_eq!(g.cut_size(&side).unwrap(), size);
+        assert!(2 * size >= g.edges().len(), "n = {n}");
+
+        let (side, size) = g.random_cut(&mut rng);
+        assert_eq!(side.len(), n);
+        assert_eq!(g.cut_size(&side).unwrap(), size);
+    }
+    // Cykl parzystej długości jest dwudzielny.
+    assert_eq!(cycle(6).derandomized_cut().1, 6);
+}
+
+#[test]
+fn karger_finds_the_bridge() {
+    let adj = barbell(5);
+    let mut rng = StdRng::seed_from_u64(3);
+    assert_eq!(min_cut(&adj, 200, &mut rng).unwrap(), 1);
+    for _ in 0..20 {
+        assert!(karger_contraction(&adj, &mut rng).unwrap() >= 1);
+    }
+}
+
+#[test]
+fn invalid_karger_input() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let single = HashMap::from([(0, vec![])]);
+    assert!(matches!(
+        karger_contraction(&single, &mut rng),
+        Err(Error::InvalidGraph(_))
+    ));
+    let dangling = HashMap::from([(0, vec![1]), (1, vec![0, 2])]);
+    assert!(matches!(
+        min_cut(&dangling, 1, &mut rng),
+        Err(Error::InvalidGraph(_))
+    ));
+    assert!(matches!(
+        min_cut(&barbell(2), 0, &mut rng),
+        Err(Error::InvalidParameter(_))
+    ));
+    let disconnected = HashMap::from([(0, vec![1]), (1, vec![0]), (2, vec![])]);
+    assert_eq!(min_cut(&disconnected, 5, &mut rng).unwrap(), 0);
+}
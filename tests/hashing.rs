@@ -0,0 +1,67 @@
+use std::cmp::Ordering;
+
+use algorithmics::strings::RollingHashIndex;
+use algorithmics::strings::hashing::{MERSENNE_61, PRIME_62};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn brute_force_lce(text: &[u8], i: usize, j: usize) -> usize {
+    text[i..]
+        .iter()
+        .zip(&text[j..])
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+#[test]
+fn queries_match_direct_comparison() {
+    let mut rng = StdRng::seed_from_u64(13);
+    for _ in 0..50 {
+        let len = rng.random_range(0..60);
+        // Mały alfabet daje dużo powtórzeń, a więc długie LCE.
+        let text: Vec<u8> = (0..len).map(|_| b"ab"[rng.random_range(0..2)]).collect();
+        let index = RollingHashIndex::new(&text, &mut rng);
+        assert_eq!(index.len(), text.len());
+        for _ in 0..200 {
+            let (i, j) = (rng.random_range(0..=len), rng.random_range(0..=len));
+            assert_eq!(index.lce(i, j), brute_force_lce(&text, i, j), "{text:?}");
+            let (a_len, b_len) = (rng.random_range(0..=len - i), rng.random_range(0..=len - j));
+            let (a, b) = (i..i + a_len, j..j + b_len);
+            assert_eq!(
+                index.eq(a.clone(), b.clone()),
+                text[a.clone()] == text[b.clone()]
+            );
+            assert_eq!(
+                index.compare(&text, a.clone(), b.clone()),
+                text[a.clone()].cmp(&text[b.clone()]),
+                "{a:?} {b:?} w {text:?}"
+            );
+            if text[a.clone()] == text[b.clone()] {
+                assert_eq!(index.hash(a), index.hash(b));
+            }
+        }
+    }
+}
+
+#[test]
+fn chars_and_shared_hashers() {
+    let a: Vec<char> = "żółw i żółwik".chars().collect();
+    let b: Vec<char> = "mały żółw".chars().collect();
+    let hashers = RollingHashIndex::random_hashers(&mut StdRng::seed_from_u64(14));
+    let ia = RollingHashIndex::with_hashers(&a, hashers);
+    let ib = RollingHashIndex::with_hashers(&b, hashers);
+    // Hasze tego samego słowa w różnych tekstach są równe.
+    assert_eq!(ia.hash(0..4), ib.hash(5..9));
+    assert_ne!(ia.hash(0..4), ib.hash(4..8));
+    assert_eq!(ia.lce(0, 7), 4);
+    assert_eq!(ia.compare(&a, 0..4, 7..13), Ordering::Less);
+
+    assert_eq!(hashers[0].modulus(), MERSENNE_61);
+    assert_eq!(hashers[1].modulus(), PRIME_62);
+    let seeded = RollingHashIndex::with_seed(&a, 15);
+    assert_eq!(
+        seeded.hashers(),
+        RollingHashIndex::with_seed(&b, 15).hashers()
+    );
+    assert!(RollingHashIndex::with_seed::<u8>(&[], 15).is_empty());
+}
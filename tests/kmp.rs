@@ -0,0 +1,107 @@
+use algorithmics::dna::parse_sequence;
+use algorithmics::strings::{Kmp, Matcher, kmp_search, prefix_function};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Wszystkie wystąpienia `pattern` w `text`, porównaniem na każdej pozycji.
+fn brute_force<T: PartialEq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&i| text[i..].starts_with(pattern))
+        .collect()
+}
+
+/// Funkcja prefiksowa z definicji: najdłuższy właściwy brzeg każdego prefiksu.
+fn brute_force_prefix_function<T: PartialEq>(p: &[T]) -> Vec<usize> {
+    (1..=p.len())
+        .map(|len| {
+            let prefix = &p[..len];
+            (0..len)
+                .rev()
+                .find(|&k| prefix.ends_with(&prefix[..k]))
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Zachłanny wybór rozłącznych wystąpień od lewej.
+fn non_overlapping(all: &[usize], m: usize) -> Vec<usize> {
+    let mut wynik = Vec::new();
+    for &pos in all {
+        if wynik.last().is_none_or(|&last| pos >= last + m) {
+            wynik.push(pos);
+        }
+    }
+    wynik
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[char], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_prefix_functions() {
+    assert_eq!(prefix_function(b"abacaba"), [0, 0, 1, 0, 1, 2, 3]);
+    assert_eq!(prefix_function(b"aaaa"), [0, 1, 2, 3]);
+    assert_eq!(prefix_function::<u8>(&[]), Vec::<usize>::new());
+    // Dla wzorca z polskimi znakami funkcja liczona jest na bajtach UTF-8.
+    assert_eq!(
+        Kmp::new("ąą").prefix_function(),
+        prefix_function("ąą".as_bytes())
+    );
+}
+
+#[test]
+fn generic_search_matches_brute_force() {
+    let mut rng = StdRng::seed_from_u64(5);
+    for _ in 0..500 {
+        let pattern: Vec<char> = random_word(&mut rng, &['a', 'b', 'ą'], 5).chars().collect();
+        let text: Vec<char> = random_word(&mut rng, &['a', 'b', 'ą'], 30)
+            .chars()
+            .collect();
+        assert_eq!(
+            prefix_function(&pattern),
+            brute_force_prefix_function(&pattern)
+        );
+        assert_eq!(
+            kmp_search(&text, &pattern),
+            brute_force(&text, &pattern),
+            "{pattern:?} w {text:?}"
+        );
+    }
+    let genome = parse_sequence("ATATGCATATAT").unwrap();
+    let motif = parse_sequence("ATAT").unwrap();
+    assert_eq!(kmp_search(&genome, &motif), [0, 6, 8]);
+}
+
+#[test]
+fn matcher_reports_byte_offsets() {
+    let mut rng = StdRng::seed_from_u64(6);
+    for _ in 0..500 {
+        let pattern = random_word(&mut rng, &['a', 'b', 'ą'], 4);
+        let text = random_word(&mut rng, &['a', 'b', 'ą'], 30);
+        let want: Vec<usize> = brute_force(text.as_bytes(), pattern.as_bytes())
+            .into_iter()
+            .filter(|&i| text.is_char_boundary(i))
+            .collect();
+        let kmp = Kmp::new(&pattern);
+        assert_eq!(
+            kmp.find_iter(&text).collect::<Vec<_>>(),
+            want,
+            "{pattern:?} w {text:?}"
+        );
+        assert_eq!(
+            kmp.find_non_overlapping_iter(&text).collect::<Vec<_>>(),
+            non_overlapping(&want, pattern.len()),
+            "{pattern:?} w {text:?}"
+        );
+    }
+    let kmp = Kmp::new("ąb");
+    assert_eq!(
+        kmp.find_iter(&[0xC4, 0xC4, 0x85, b'b', 0xFF][..])
+            .collect::<Vec<_>>(),
+        [1]
+    );
+}
@@ -0,0 +1,81 @@
+use algorithmics::Error;
+use algorithmics::lcs::{average_lcs, generate_binary_sequences, lcs, lcs3, total_lcs};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Czy `sub` jest podciągiem (niekoniecznie spójnym) `s`.
+fn is_subsequence(sub: &str, s: &str) -> bool {
+    let mut chars = s.chars();
+    sub.chars().all(|c| chars.any(|d| d == c))
+}
+
+/// Długość LCS trzech ciągów przez sprawdzenie wszystkich podciągów `a`.
+fn brute_force_lcs3(a: &str, b: &str, c: &str) -> usize {
+    let chars: Vec<char> = a.chars().collect();
+    (0..1usize << chars.len())
+        .map(|mask| {
+            (0..chars.len())
+                .filter(|&i| mask >> i & 1 == 1)
+                .map(|i| chars[i])
+                .collect::<String>()
+        })
+        .filter(|sub| is_subsequence(sub, b) && is_subsequence(sub, c))
+        .map(|sub| sub.chars().count())
+        .max()
+        .unwrap()
+}
+
+fn random_word(rng: &mut StdRng, max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| ['a', 'b', 'c'][rng.random_range(0..3)])
+        .collect()
+}
+
+#[test]
+fn binary_sequences_are_listed_in_order() {
+    assert_eq!(
+        generate_binary_sequences(2).unwrap(),
+        vec!["00", "01", "10", "11"]
+    );
+    assert!(matches!(
+        generate_binary_sequences(usize::BITS as usize),
+        Err(Error::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn known_lcs_lengths() {
+    assert_eq!(lcs("ABCBDAB", "BDCABA"), 4);
+    assert_eq!(lcs("", "abc"), 0);
+    assert_eq!(lcs("abc", "abc"), 3);
+    assert_eq!(lcs3("abcd", "acbd", "abd"), "abd");
+}
+
+#[test]
+fn average_over_binary_sequences() {
+    // Wartości wypisywane przez program 8.
+    let sequences = generate_binary_sequences(5).unwrap();
+    assert_eq!(total_lcs(&sequences), (3324, 1024));
+    assert_eq!(average_lcs(&sequences).unwrap(), 3324.0 / 1024.0);
+    assert_eq!(total_lcs(&[]), (0, 0));
+    assert!(matches!(average_lcs(&[]), Err(Error::InvalidParameter(_))));
+}
+
+#[test]
+fn lcs3_is_a_longest_common_subsequence() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..200 {
+        let a = random_word(&mut rng, 8);
+        let b = random_word(&mut rng, 8);
+        let c = random_word(&mut rng, 8);
+        let common = lcs3(&a, &b, &c);
+        assert!(
+            [&a, &b, &c].iter().all(|s| is_subsequence(&common, s)),
+            "{common:?} w {a:?}, {b:?}, {c:?}"
+        );
+        assert_eq!(common.chars().count(), brute_force_lcs3(&a, &b, &c));
+        assert_eq!(lcs(&a, &b), lcs(&b, &a));
+        assert!(lcs(&a, &b) >= common.len());
+    }
+}
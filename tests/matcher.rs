@@ -0,0 +1,95 @@
+use algorithmics::strings::{
+    BoyerMoore, Horspool, Kmp, Matcher, Naive, PrefixAutomaton, RabinKarp, naive_match,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Wszystkie wystąpienia `pattern` w `haystack`, porównaniem na każdej pozycji
+/// bajtowej. Pusty wzorzec sprawdzany jest tylko na poprawnym UTF-8, gdzie
+/// występuje na każdej granicy znaku.
+fn brute_force(haystack: &[u8], pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() {
+        let text = std::str::from_utf8(haystack).unwrap();
+        return (0..=text.len())
+            .filter(|&i| text.is_char_boundary(i))
+            .collect();
+    }
+    (0..=haystack.len())
+        .filter(|&i| haystack[i..].starts_with(pattern.as_bytes()))
+        .collect()
+}
+
+/// Sprawdza wszystkie metody traitu dla implementacji `M`.
+fn check<M: Matcher>(name: &str, haystack: &[u8], pattern: &str) {
+    let want = brute_force(haystack, pattern);
+    let matcher = M::compile(pattern);
+    assert_eq!(
+        matcher.find_iter(haystack).collect::<Vec<_>>(),
+        want,
+        "{name} {pattern:?} w {haystack:?}"
+    );
+    assert_eq!(
+        matcher.find_first(haystack),
+        want.first().copied(),
+        "{name}"
+    );
+    assert_eq!(matcher.count(haystack), want.len(), "{name}");
+    assert_eq!(matcher.is_match(haystack), !want.is_empty(), "{name}");
+}
+
+fn check_all(haystack: &[u8], pattern: &str) {
+    check::<Naive>("Naive", haystack, pattern);
+    check::<RabinKarp>("RabinKarp", haystack, pattern);
+    check::<PrefixAutomaton>("PrefixAutomaton", haystack, pattern);
+    check::<Kmp>("Kmp", haystack, pattern);
+    check::<BoyerMoore>("BoyerMoore", haystack, pattern);
+    check::<Horspool>("Horspool", haystack, pattern);
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[&str], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn naive_match_finds_first_occurrence() {
+    assert_eq!(naive_match("ala ma kota", "ma"), Some(4));
+    assert_eq!(naive_match("żółw", "łw"), Some(4));
+    assert_eq!(naive_match("ab", "abc"), None);
+    assert_eq!(naive_match("ab", ""), Some(0));
+}
+
+#[test]
+fn all_matchers_agree_with_brute_force_on_text() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let alphabet = ["a", "b", "ą", "ż"];
+    for _ in 0..500 {
+        let pattern = random_word(&mut rng, &alphabet, 5);
+        let text = random_word(&mut rng, &alphabet, 30);
+        check_all(text.as_bytes(), &pattern);
+        assert_eq!(
+            naive_match(&text, &pattern),
+            brute_force(text.as_bytes(), &pattern).first().copied()
+        );
+    }
+}
+
+#[test]
+fn all_matchers_agree_with_brute_force_on_bytes() {
+    let mut rng = StdRng::seed_from_u64(8);
+    // Samotny pierwszy bajt "ą" (0xC4) i 0xFF to niepoprawne sekwencje UTF-8.
+    let pieces: [&[u8]; 5] = [b"a", b"b", "ą".as_bytes(), &[0xC4], &[0xFF]];
+    for _ in 0..500 {
+        let mut pattern = random_word(&mut rng, &["a", "b", "ą"], 4);
+        if pattern.is_empty() {
+            pattern.push('ą');
+        }
+        let len = rng.random_range(0..=25);
+        let haystack: Vec<u8> = (0..len)
+            .flat_map(|_| pieces[rng.random_range(0..pieces.len())].iter().copied())
+            .collect();
+        check_all(&haystack, &pattern);
+    }
+}
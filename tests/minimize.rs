@@ -0,0 +1,79 @@
+use algorithmics::strings::regex::Dfa;
+use algorithmics::strings::{Automaton, PrefixAutomaton, counterexample, equivalent, minimize};
+
+const PATTERNS: &[&str] = &[
+    "(a|b)*abb",
+    "a*",
+    "a*a",
+    "(aa)*",
+    "(a|b)*a(a|b)(a|b)",
+    "[a-c]+|ą",
+    "[^ab]*b",
+    "(ab|ba)*",
+    "(|a)(|a)(|a)",
+    ".*",
+    "",
+    "x(y|z)*x|xx",
+];
+
+/// Wszystkie słowa nad `alphabet` długości co najwyżej `max_len`.
+fn words(alphabet: &[char], max_len: usize) -> Vec<String> {
+    let mut wynik = vec![String::new()];
+    let mut layer = vec![String::new()];
+    for _ in 0..max_len {
+        layer = layer
+            .iter()
+            .flat_map(|w| alphabet.iter().map(move |&c| format!("{w}{c}")))
+            .collect();
+        wynik.extend(layer.iter().cloned());
+    }
+    wynik
+}
+
+#[test]
+fn minimized_dfa_is_equivalent_and_stable() {
+    for &pattern in PATTERNS {
+        let dfa = Dfa::new(pattern).unwrap();
+        let min = minimize(&dfa);
+        assert!(equivalent(&min, &dfa), "{pattern:?}");
+        assert_eq!(counterexample(&min, &dfa), None, "{pattern:?}");
+        assert!(min.states() <= dfa.states(), "{pattern:?}");
+        assert_eq!(minimize(&min).states(), min.states(), "{pattern:?}");
+        for w in words(&['a', 'b', 'ą'], 5) {
+            assert_eq!(
+                min.is_full_match(&w),
+                dfa.is_full_match(&w),
+                "{pattern:?} na {w:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn known_minimal_sizes() {
+    assert_eq!(minimize(&Dfa::new("(a|b)*abb").unwrap()).states(), 4);
+    assert_eq!(minimize(&Dfa::new("(a|b)*").unwrap()).states(), 1);
+    assert_eq!(minimize(&Dfa::new("a|b|ab|bb").unwrap()).states(), 3);
+    // Automat prefiksowy jest już minimalny.
+    let prefix = PrefixAutomaton::new("abcab");
+    assert_eq!(minimize(&prefix).states(), prefix.state_count());
+}
+
+#[test]
+fn counterexample_is_shortest_distinguishing_word() {
+    let star = Dfa::new("a*").unwrap();
+    let plus = Dfa::new("a*a").unwrap();
+    assert_eq!(counterexample(&star, &plus), Some(String::new()));
+    assert!(!equivalent(&star, &plus));
+
+    let even = Dfa::new("(aa)*").unwrap();
+    assert_eq!(counterexample(&even, &star), Some("a".to_string()));
+
+    let a = Dfa::new("(a|b)*abb").unwrap();
+    let b = Dfa::new("(a|b)*bb").unwrap();
+    assert_eq!(counterexample(&a, &b), Some("bb".to_string()));
+
+    let same = Dfa::new("(a|b)*").unwrap();
+    let also = Dfa::new("(a*b*)*").unwrap();
+    assert_eq!(counterexample(&same, &also), None);
+}
@@ -0,0 +1,68 @@
+use algorithmics::Error;
+use algorithmics::montecarlo::{
+    QUARTER_CIRCLE_AREA, RunningStats, mc_1d_antithetic, mc_1d_basic, mc_1d_stratified,
+    mc_2d_antithetic, mc_2d_basic, mc_2d_stratified, sin_integral,
+};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+type Estimator = fn(&mut StdRng) -> f64;
+
+#[test]
+fn running_stats_match_two_pass_formulas() {
+    let xs = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    let stats: RunningStats = xs.iter().copied().collect();
+    assert_eq!(stats.count(), 8);
+    assert!((stats.mean() - 5.0).abs() < 1e-12);
+    assert!((stats.var() - 4.0).abs() < 1e-12);
+    let empty = RunningStats::new();
+    assert_eq!((empty.count(), empty.mean(), empty.var()), (0, 0.0, 0.0));
+}
+
+#[test]
+fn estimators_are_reproducible_and_close() {
+    let estimators: [(&str, Estimator); 6] = [
+        ("1d basic", |r| mc_1d_basic(40_000, r).unwrap()),
+        ("1d stratified", |r| {
+            mc_1d_stratified(40_000, 100, r).unwrap()
+        }),
+        ("1d antithetic", |r| mc_1d_antithetic(40_000, r).unwrap()),
+        ("2d basic", |r| mc_2d_basic(40_000, r).unwrap()),
+        ("2d stratified", |r| {
+            mc_2d_stratified(40_000, 10, r).unwrap()
+        }),
+        ("2d antithetic", |r| mc_2d_antithetic(40_000, r).unwrap()),
+    ];
+    for (name, estimate) in estimators {
+        let a = estimate(&mut StdRng::seed_from_u64(5));
+        let b = estimate(&mut StdRng::seed_from_u64(5));
+        assert_eq!(a, b, "{name}");
+        assert!((a - QUARTER_CIRCLE_AREA).abs() < 0.02, "{name}: {a}");
+    }
+    let a = sin_integral(40_000, &mut StdRng::seed_from_u64(6)).unwrap();
+    assert_eq!(
+        a,
+        sin_integral(40_000, &mut StdRng::seed_from_u64(6)).unwrap()
+    );
+    assert!((a - 2.0).abs() < 0.05, "{a}");
+}
+
+#[test]
+fn invalid_sample_counts() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let errors = [
+        sin_integral(0, &mut rng),
+        mc_1d_basic(0, &mut rng),
+        mc_1d_stratified(10, 0, &mut rng),
+        mc_1d_stratified(10, 11, &mut rng),
+        mc_1d_antithetic(0, &mut rng),
+        mc_1d_antithetic(3, &mut rng),
+        mc_2d_basic(0, &mut rng),
+        mc_2d_stratified(10, 4, &mut rng),
+        mc_2d_stratified(10, usize::MAX, &mut rng),
+        mc_2d_antithetic(7, &mut rng),
+    ];
+    for (i, result) in errors.into_iter().enumerate() {
+        assert!(matches!(result, Err(Error::InvalidParameter(_))), "{i}");
+    }
+}
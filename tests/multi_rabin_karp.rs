@@ -0,0 +1,79 @@
+use algorithmics::strings::MultiRabinKarp;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Pary (numer wzorca, pozycja w bajtach) wyznaczone porównaniem na każdej
+/// granicy znaku, posortowane jak w `MultiRabinKarp::find_all`.
+fn brute_force(text: &str, patterns: &[String]) -> Vec<(usize, usize)> {
+    let mut wynik = Vec::new();
+    for pos in (0..=text.len()).filter(|&i| text.is_char_boundary(i)) {
+        for (id, p) in patterns.iter().enumerate() {
+            if text[pos..].starts_with(p.as_str()) {
+                wynik.push((id, pos));
+            }
+        }
+    }
+    wynik
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[char], len: usize) -> String {
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn mixed_lengths_and_duplicates() {
+    let patterns: Vec<String> = ["ma", "kota", "ą", "ma", "", "a ma k", "ąą"]
+        .map(String::from)
+        .to_vec();
+    let text = "ala ma kota, ąąą ma kota";
+    let mrk = MultiRabinKarp::with_seed(&patterns, 11);
+    assert_eq!(mrk.pattern_count(), patterns.len());
+    assert_eq!(mrk.find_all(text), brute_force(text, &patterns));
+}
+
+#[test]
+fn random_mixed_length_sets_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let alphabet = ['a', 'b', 'ą', 'ż'];
+    for _ in 0..200 {
+        let count = rng.random_range(1..=8);
+        let mut patterns: Vec<String> = (0..count)
+            .map(|_| {
+                let len = rng.random_range(0..=4);
+                random_word(&mut rng, &alphabet, len)
+            })
+            .collect();
+        // Powtórzony wzorzec zgłaszany jest pod każdym swoim numerem.
+        let duplicate = patterns[rng.random_range(0..count)].clone();
+        patterns.push(duplicate);
+        let len = rng.random_range(0..=40);
+        let text = random_word(&mut rng, &alphabet, len);
+        let mrk = MultiRabinKarp::new(&patterns, &mut rng);
+        assert_eq!(
+            mrk.find_all(&text),
+            brute_force(&text, &patterns),
+            "{patterns:?} w {text:?}"
+        );
+    }
+}
+
+#[test]
+fn thousands_of_fixed_length_fingerprints() {
+    let mut rng = StdRng::seed_from_u64(8);
+    let alphabet = ['a', 'b', 'c', 'ę'];
+    let document = random_word(&mut rng, &alphabet, 5000);
+    let mut fingerprints: Vec<String> = (0..3000)
+        .map(|_| random_word(&mut rng, &alphabet, 6))
+        .collect();
+    // Część odcisków na pewno występuje w dokumencie.
+    let chars: Vec<char> = document.chars().collect();
+    for start in (0..chars.len() - 6).step_by(500) {
+        fingerprints.push(chars[start..start + 6].iter().collect());
+    }
+    let mrk = MultiRabinKarp::with_seed(&fingerprints, 9);
+    let found = mrk.find_all(&document);
+    assert!(found.len() >= 10);
+    assert_eq!(found, brute_force(&document, &fingerprints));
+}
@@ -0,0 +1,86 @@
+use algorithmics::strings::{
+    borders, is_prefix, is_primitive, is_suffix, overlap, primitive_root, shortest_period,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn brute_force_overlap(x: &[u8], y: &[u8]) -> usize {
+    (0..=x.len().min(y.len()))
+        .rev()
+        .find(|&k| x[..k] == y[y.len() - k..])
+        .unwrap()
+}
+
+fn brute_force_borders(s: &[u8]) -> Vec<usize> {
+    (1..s.len())
+        .rev()
+        .filter(|&k| s[..k] == s[s.len() - k..])
+        .collect()
+}
+
+fn brute_force_period(s: &[u8]) -> usize {
+    (1..=s.len())
+        .find(|&p| (0..s.len() - p).all(|i| s[i] == s[i + p]))
+        .unwrap_or(0)
+}
+
+/// Najkrótsze `u`, dla którego `s == u^k`.
+fn brute_force_root(s: &[u8]) -> &[u8] {
+    (1..=s.len())
+        .find(|&p| s.len().is_multiple_of(p) && s.chunks(p).all(|c| c == &s[..p]))
+        .map_or(s, |p| &s[..p])
+}
+
+fn random_word(rng: &mut StdRng, max_len: usize) -> Vec<u8> {
+    let len = rng.random_range(0..=max_len);
+    // Ciągi okresowe trafiają się często przy dwuliterowym alfabecie i
+    // przy powielaniu krótkiego słowa.
+    if rng.random_bool(0.3) && len > 0 {
+        let unit: Vec<u8> = (0..rng.random_range(1..=3))
+            .map(|_| b"ab"[rng.random_range(0..2)])
+            .collect();
+        return unit.iter().copied().cycle().take(len).collect();
+    }
+    (0..len).map(|_| b"ab"[rng.random_range(0..2)]).collect()
+}
+
+#[test]
+fn predicates_from_the_c_programs() {
+    assert!(is_prefix(b"ab", b"abc"));
+    assert!(!is_prefix(b"abc", b"ab"));
+    assert!(is_suffix(b"bc", b"abc"));
+    assert!(is_prefix(b"", b"") && is_suffix(b"", b"x"));
+    assert_eq!(overlap(b"abcde", b"xxabc"), 3);
+    assert_eq!(overlap(b"aaa", b"aa"), 2);
+    assert_eq!(overlap("ółw".as_bytes(), "żół".as_bytes()), 4);
+}
+
+#[test]
+fn borders_periods_and_roots() {
+    assert_eq!(borders(b"abacaba"), [3, 1]);
+    assert_eq!(shortest_period(b"abaab"), 3);
+    assert_eq!(shortest_period::<u8>(&[]), 0);
+    assert!(is_primitive(b"aab"));
+    assert!(!is_primitive(b"abab"));
+    assert!(!is_primitive::<u8>(&[]));
+    assert_eq!(primitive_root(b"abcabcabc"), b"abc");
+}
+
+#[test]
+fn random_words_match_definitions() {
+    let mut rng = StdRng::seed_from_u64(15);
+    for _ in 0..2000 {
+        let x = random_word(&mut rng, 12);
+        let y = random_word(&mut rng, 12);
+        assert_eq!(overlap(&x, &y), brute_force_overlap(&x, &y), "{x:?} {y:?}");
+        assert_eq!(borders(&x), brute_force_borders(&x), "{x:?}");
+        assert_eq!(shortest_period(&x), brute_force_period(&x), "{x:?}");
+        let root = brute_force_root(&x);
+        assert_eq!(primitive_root(&x), root, "{x:?}");
+        assert_eq!(
+            is_primitive(&x),
+            !x.is_empty() && root.len() == x.len(),
+            "{x:?}"
+        );
+    }
+}
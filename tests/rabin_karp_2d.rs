@@ -0,0 +1,87 @@
+use algorithmics::Error;
+use algorithmics::strings::{Grid, rabin_karp_2d};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Położenia bloku `pattern` w `text` wyznaczone porównaniem w każdym miejscu.
+fn brute_force(text: &Grid, pattern: &Grid) -> Vec<(usize, usize)> {
+    let (pr, pc) = (pattern.rows(), pattern.cols());
+    let mut wynik = Vec::new();
+    if pr > text.rows() || pc > text.cols() {
+        return wynik;
+    }
+    for i in 0..=text.rows() - pr {
+        for j in 0..=text.cols() - pc {
+            if (0..pr).all(|k| text.row(i + k)[j..j + pc] == *pattern.row(k)) {
+                wynik.push((i, j));
+            }
+        }
+    }
+    wynik
+}
+
+fn random_grid(rng: &mut StdRng, rows: usize, cols: usize, alphabet: &[u8]) -> Grid {
+    let data = (0..rows * cols)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect();
+    Grid::new(data, cols).unwrap()
+}
+
+#[test]
+fn grid_construction() {
+    let grid = Grid::from_rows(&["abc", "def"]).unwrap();
+    assert_eq!((grid.rows(), grid.cols()), (2, 3));
+    assert_eq!(grid.row(1), b"def");
+    assert_eq!(Grid::new(b"abcdef".to_vec(), 3).unwrap(), grid);
+    assert!(matches!(
+        Grid::new(b"abcde".to_vec(), 3),
+        Err(Error::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        Grid::new(b"a".to_vec(), 0),
+        Err(Error::InvalidParameter(_))
+    ));
+    assert!(matches!(
+        Grid::from_rows(&["ab", "c"]),
+        Err(Error::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn known_blocks() {
+    let mut rng = StdRng::seed_from_u64(12);
+    let text = Grid::from_rows(&["abab", "baba", "abab"]).unwrap();
+    let pattern = Grid::from_rows(&["ab", "ba"]).unwrap();
+    assert_eq!(
+        rabin_karp_2d(&text, &pattern, &mut rng),
+        [(0, 0), (0, 2), (1, 1)]
+    );
+    let too_wide = Grid::from_rows(&["ababa"]).unwrap();
+    assert!(rabin_karp_2d(&text, &too_wide, &mut rng).is_empty());
+    // Wzorzec bez kolumn mieści się w każdym położeniu.
+    let empty = Grid::from_rows(&["", ""]).unwrap();
+    assert_eq!(rabin_karp_2d(&text, &empty, &mut rng).len(), 2 * 5);
+}
+
+#[test]
+fn random_grids_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(13);
+    for _ in 0..500 {
+        let (tr, tc) = (rng.random_range(0..8), rng.random_range(0..8));
+        let (pr, pc) = (rng.random_range(0..4), rng.random_range(0..4));
+        let text = random_grid(&mut rng, tr, tc, b"ab");
+        let pattern = if pr <= tr && pc <= tc && rng.random_bool(0.5) {
+            // Blok wycięty z tekstu na pewno występuje.
+            let (i, j) = (rng.random_range(0..=tr - pr), rng.random_range(0..=tc - pc));
+            let rows: Vec<&[u8]> = (i..i + pr).map(|k| &text.row(k)[j..j + pc]).collect();
+            Grid::from_rows(&rows).unwrap()
+        } else {
+            random_grid(&mut rng, pr, pc, b"ab")
+        };
+        assert_eq!(
+            rabin_karp_2d(&text, &pattern, &mut rng),
+            brute_force(&text, &pattern),
+            "{pattern:?} w {text:?}"
+        );
+    }
+}
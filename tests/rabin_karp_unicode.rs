@@ -0,0 +1,43 @@
+use algorithmics::strings::{rabin_karp, rabin_karp_chars};
+
+// Haszowanie bajtów trafia tu na okna zaczynające się w środku znaku
+// (np. drugi bajt "ą"); wcześniej weryfikacja `&text[i..i + m]` wtedy panikowała.
+#[test]
+fn byte_mode_does_not_panic_inside_multibyte_chars() {
+    let text = "ala ma kota, ąłę ma kota";
+    assert_eq!(rabin_karp(text, "kot"), vec![7, 23]);
+    assert_eq!(rabin_karp("źdźbło", "bło"), vec![5]);
+    assert_eq!(rabin_karp("łąka", "x"), Vec::<usize>::new());
+}
+
+#[test]
+fn byte_offsets_are_char_boundaries() {
+    let text = "żółw żółć żółw";
+    let positions = rabin_karp(text, "żółw");
+    assert_eq!(positions, vec![0, 17]);
+    for &i in &positions {
+        assert!(text[i..].starts_with("żółw"));
+    }
+}
+
+#[test]
+fn char_mode_returns_char_offsets() {
+    assert_eq!(rabin_karp_chars("ąkot", "kot"), vec![1]);
+    assert_eq!(rabin_karp_chars("żółw żółć żółw", "żółw"), vec![0, 10]);
+    assert_eq!(rabin_karp_chars("ąąą", "ąą"), vec![0, 1]);
+}
+
+#[test]
+fn both_modes_agree_on_non_ascii_text() {
+    let text = "Zażółć gęślą jaźń, gęślą jaźń";
+    let bytes = rabin_karp(text, "gęślą");
+    let chars = rabin_karp_chars(text, "gęślą");
+    let char_offsets: Vec<usize> = bytes.iter().map(|&b| text[..b].chars().count()).collect();
+    assert_eq!(char_offsets, chars);
+}
+
+#[test]
+fn empty_pattern_matches_every_boundary() {
+    assert_eq!(rabin_karp("ąb", ""), vec![0, 2, 3]);
+    assert_eq!(rabin_karp_chars("ąb", ""), vec![0, 1, 2]);
+}
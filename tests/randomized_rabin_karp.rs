@@ -0,0 +1,106 @@
+use algorithmics::strings::hashing::{MERSENNE_61, add_mod, mul_mod, sub_mod};
+use algorithmics::strings::{Matcher, PolyHash, RabinKarpOptions, RandomizedRabinKarp};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const OPTIONS: [RabinKarpOptions; 4] = [
+    RabinKarpOptions {
+        double_hashing: false,
+        monte_carlo: false,
+    },
+    RabinKarpOptions {
+        double_hashing: true,
+        monte_carlo: false,
+    },
+    RabinKarpOptions {
+        double_hashing: false,
+        monte_carlo: true,
+    },
+    RabinKarpOptions {
+        double_hashing: true,
+        monte_carlo: true,
+    },
+];
+
+/// Wszystkie wystąpienia `pattern` w `text` na granicach znaków.
+fn brute_force(text: &str, pattern: &str) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&i| text.is_char_boundary(i) && text[i..].starts_with(pattern))
+        .collect()
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[char], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn modular_arithmetic_matches_u128() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let p = MERSENNE_61 as u128;
+    for _ in 0..10_000 {
+        let a = rng.random_range(0..MERSENNE_61);
+        let b = rng.random_range(0..MERSENNE_61);
+        assert_eq!(mul_mod(a, b) as u128, a as u128 * b as u128 % p);
+        assert_eq!(add_mod(a, b) as u128, (a as u128 + b as u128) % p);
+        assert_eq!(sub_mod(a, b) as u128, (a as u128 + p - b as u128) % p);
+    }
+    assert_eq!(mul_mod(MERSENNE_61 - 1, MERSENNE_61 - 1), 1);
+}
+
+#[test]
+fn rolling_hash_equals_hash_of_window() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let hs = PolyHash::random(&mut rng);
+    let text: Vec<u8> = (0..200).map(|_| rng.random()).collect();
+    for m in 1..10 {
+        let pow = hs.pow(m - 1);
+        let mut hash = hs.hash_bytes(&text[..m]);
+        for i in 0..text.len() - m {
+            hash = hs.roll(hash, text[i] as u64, text[i + m] as u64, pow);
+            assert_eq!(hash, hs.hash_bytes(&text[i + 1..i + 1 + m]));
+        }
+    }
+    // Przy podstawie 1 hash to suma bajtów, więc anagramy kolidują.
+    let sum = PolyHash::new(1);
+    assert_eq!(sum.hash_bytes(b"ab"), sum.hash_bytes(b"ba"));
+}
+
+#[test]
+fn every_option_matches_brute_force() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let alphabet = ['a', 'b', 'ą'];
+    for _ in 0..300 {
+        let pattern = random_word(&mut rng, &alphabet, 5);
+        let text = random_word(&mut rng, &alphabet, 40);
+        let want = brute_force(&text, &pattern);
+        for options in OPTIONS {
+            let rk = RandomizedRabinKarp::new(&pattern, options, &mut rng);
+            assert_eq!(
+                rk.find_iter(&text).collect::<Vec<_>>(),
+                want,
+                "{options:?} {pattern:?} w {text:?}"
+            );
+        }
+        let rk = RandomizedRabinKarp::compile(&pattern);
+        assert_eq!(rk.count(&text), want.len());
+    }
+}
+
+#[test]
+fn seeded_hashers_are_reproducible() {
+    for options in OPTIONS {
+        let a = RandomizedRabinKarp::with_seed("kot", options, 12);
+        let b = RandomizedRabinKarp::with_seed("kot", options, 12);
+        assert_eq!(a.hashers(), b.hashers());
+        assert_eq!(
+            a.hashers().len(),
+            if options.double_hashing { 2 } else { 1 }
+        );
+    }
+    let a = RandomizedRabinKarp::with_seed("kot", RabinKarpOptions::default(), 12);
+    let b = RandomizedRabinKarp::with_seed("kot", RabinKarpOptions::default(), 13);
+    assert_ne!(a.hashers(), b.hashers());
+}
@@ -0,0 +1,106 @@
+use algorithmics::Error;
+use algorithmics::strings::regex::{Dfa, Nfa, parse};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn error_position(pattern: &str) -> Option<usize> {
+    match parse(pattern) {
+        Err(Error::InvalidRegex { position, .. }) => Some(position),
+        _ => None,
+    }
+}
+
+#[test]
+fn syntax_errors_report_position() {
+    assert_eq!(error_position("("), Some(0));
+    assert_eq!(error_position("ab(c|d"), Some(2));
+    assert_eq!(error_position(")"), Some(0));
+    assert_eq!(error_position("a|b)c"), Some(3));
+    assert_eq!(error_position("[a"), Some(0));
+    assert_eq!(error_position("x[^ab"), Some(1));
+    assert_eq!(error_position("\\"), Some(0));
+    assert_eq!(error_position("ab\\"), Some(2));
+    assert_eq!(error_position("*a"), Some(0));
+    assert_eq!(error_position("a|+"), Some(2));
+    // Pozycje liczone są w bajtach.
+    assert_eq!(error_position("ąę)"), Some(4));
+    assert_eq!(error_position("a(b|c)*[x-z]?"), None);
+}
+
+const PATTERNS: &[&str] = &[
+    "a",
+    "ab|ba",
+    "(a|b)*abb",
+    "[a-c]+",
+    "[^ab]",
+    "[^ab]+b",
+    "a.c",
+    ".*ą",
+    "ż(ó|o)łw?",
+    "[ą-ż]+",
+    "(|a)b",
+    "a(|b|)c",
+    "a|",
+    "(a|)*",
+    "",
+    "x?",
+    "[\\]a-]+",
+    "\\.\\*",
+];
+
+const ALPHABET: &[char] = &[
+    'a', 'b', 'c', 'ą', 'ó', 'o', 'ż', 'ł', 'w', '.', '*', ']', '-',
+];
+
+fn random_text(rng: &mut StdRng, max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| ALPHABET[rng.random_range(0..ALPHABET.len())])
+        .collect()
+}
+
+#[test]
+fn nfa_and_dfa_agree() {
+    let mut rng = StdRng::seed_from_u64(23);
+    for &pattern in PATTERNS {
+        let nfa = Nfa::new(pattern).unwrap();
+        let dfa = Dfa::new(pattern).unwrap();
+        for _ in 0..300 {
+            let text = random_text(&mut rng, 12);
+            assert_eq!(
+                nfa.is_full_match(&text),
+                dfa.is_full_match(&text),
+                "{pattern:?} na {text:?}"
+            );
+            assert_eq!(
+                nfa.find_all(&text),
+                dfa.find_all(&text),
+                "{pattern:?} w {text:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn known_matches() {
+    let dfa = Dfa::new("(a|b)*abb").unwrap();
+    assert!(dfa.is_full_match("babb"));
+    assert!(!dfa.is_full_match("abba"));
+
+    let nfa = Nfa::new("[^ab]").unwrap();
+    assert!(nfa.is_full_match("ą"));
+    assert!(!nfa.is_full_match("a"));
+    assert_eq!(nfa.find_all("aąb"), vec![1..3]);
+
+    // Najbardziej lewe, a z nich najdłuższe dopasowania, rozłączne.
+    let nfa = Nfa::new("a|ab|abc").unwrap();
+    assert_eq!(nfa.find_all("abcab"), vec![0..3, 3..5]);
+
+    // Puste dopasowania na granicach znaków.
+    let dfa = Dfa::new("(|a)").unwrap();
+    assert_eq!(dfa.find_all("ąa"), vec![0..0, 2..3, 3..3]);
+    assert_eq!(Nfa::new("(|a)").unwrap().find_all("ąa"), dfa.find_all("ąa"));
+
+    let nfa = Nfa::new("ż(ó|o)łw?").unwrap();
+    assert_eq!(nfa.find_all("żółw żoł"), vec![0..7, 8..13]);
+}
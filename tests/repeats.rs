@@ -0,0 +1,120 @@
+use algorithmics::Error;
+use algorithmics::strings::{longest_common_substring, longest_repeated_substring};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Wszystkie podsłowa `text` o `len` znakach, jako wycinki.
+fn substrings(text: &str, len: usize) -> Vec<&str> {
+    let offsets: Vec<usize> = text
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    offsets
+        .windows(len + 1)
+        .map(|w| &text[w[0]..w[len]])
+        .collect()
+}
+
+/// Pozycje (w bajtach) wszystkich, także nakładających się, wystąpień `sub`.
+fn occurrences(text: &str, sub: &str) -> Vec<usize> {
+    (0..text.len())
+        .filter(|&i| text.is_char_boundary(i) && text[i..].starts_with(sub))
+        .collect()
+}
+
+/// Długość (w znakach) najdłuższego podsłowa występującego dwa razy.
+fn brute_force_repeated(text: &str) -> usize {
+    (1..=text.chars().count())
+        .filter(|&len| {
+            substrings(text, len)
+                .iter()
+                .any(|s| occurrences(text, s).len() >= 2)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Długość (w znakach) najdłuższego podsłowa wspólnego dla wszystkich tekstów.
+fn brute_force_common(texts: &[String]) -> usize {
+    (1..=texts[0].chars().count())
+        .filter(|&len| {
+            substrings(&texts[0], len)
+                .iter()
+                .any(|s| texts.iter().all(|t| t.contains(s)))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[char], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_repeats() {
+    let mut rng = StdRng::seed_from_u64(14);
+    let found = longest_repeated_substring("banana", &mut rng).unwrap();
+    assert_eq!(found.substring, "ana");
+    assert_eq!(found.positions, [1, 3]);
+    assert_eq!(longest_repeated_substring("abc", &mut rng), None);
+    let found = longest_repeated_substring("żółw żółw", &mut rng).unwrap();
+    assert_eq!(found.substring, "żółw");
+    assert_eq!(found.positions, [0, 8]);
+
+    let found = longest_common_substring(&["xabcy", "zabcz", "abcd"], &mut rng)
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.substring, "abc");
+    assert_eq!(found.positions, [1, 1, 0]);
+    assert_eq!(longest_common_substring(&["ab", "cd"], &mut rng), Ok(None));
+    assert!(matches!(
+        longest_common_substring(&["ab"], &mut rng),
+        Err(Error::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn random_repeats_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(15);
+    let alphabet = ['a', 'b', 'ą'];
+    for _ in 0..300 {
+        let text = random_word(&mut rng, &alphabet, 20);
+        let want = brute_force_repeated(&text);
+        match longest_repeated_substring(&text, &mut rng) {
+            None => assert_eq!(want, 0, "{text:?}"),
+            Some(found) => {
+                assert_eq!(found.substring.chars().count(), want, "{text:?}");
+                assert_eq!(found.positions, occurrences(&text, &found.substring));
+                assert!(found.positions.len() >= 2);
+            }
+        }
+    }
+}
+
+#[test]
+fn random_common_substrings_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(16);
+    let alphabet = ['a', 'b', 'ą'];
+    for _ in 0..300 {
+        let count = rng.random_range(2..=4);
+        let texts: Vec<String> = (0..count)
+            .map(|_| random_word(&mut rng, &alphabet, 12))
+            .collect();
+        let want = brute_force_common(&texts);
+        match longest_common_substring(&texts, &mut rng).unwrap() {
+            None => assert_eq!(want, 0, "{texts:?}"),
+            Some(found) => {
+                assert_eq!(found.substring.chars().count(), want, "{texts:?}");
+                let firsts: Vec<usize> = texts
+                    .iter()
+                    .map(|t| t.find(&found.substring).unwrap())
+                    .collect();
+                assert_eq!(found.positions, firsts);
+            }
+        }
+    }
+}
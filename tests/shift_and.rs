@@ -0,0 +1,131 @@
+use algorithmics::Error;
+use algorithmics::strings::{Matcher, PatternSymbol, ShiftAnd};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn accepts(symbol: &PatternSymbol, c: char) -> bool {
+    match symbol {
+        PatternSymbol::Char(p) => *p == c,
+        PatternSymbol::Class(cs) => cs.contains(&c),
+        PatternSymbol::Any => true,
+    }
+}
+
+/// Pozycje (w bajtach) znaków, od których zaczyna się fragment pasujący do
+/// kolejnych symboli wzorca.
+fn brute_force(text: &str, symbols: &[PatternSymbol]) -> Vec<usize> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut wynik: Vec<usize> = (0..chars.len())
+        .filter(|&i| {
+            i + symbols.len() <= chars.len()
+                && symbols
+                    .iter()
+                    .zip(&chars[i..])
+                    .all(|(s, &(_, c))| accepts(s, c))
+        })
+        .map(|i| chars[i].0)
+        .collect();
+    if symbols.is_empty() {
+        wynik.push(text.len());
+    }
+    wynik
+}
+
+fn random_symbols(rng: &mut StdRng, alphabet: &[char], len: usize) -> Vec<PatternSymbol> {
+    (0..len)
+        .map(|_| match rng.random_range(0..10) {
+            0 => PatternSymbol::Any,
+            1 | 2 => PatternSymbol::Class(
+                (0..rng.random_range(1..=2))
+                    .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+                    .collect(),
+            ),
+            _ => PatternSymbol::Char(alphabet[rng.random_range(0..alphabet.len())]),
+        })
+        .collect()
+}
+
+/// Tekst z kilkoma wklejonymi fragmentami pasującymi do wzorca, żeby długie
+/// wzorce też miały wystąpienia.
+fn random_text(rng: &mut StdRng, alphabet: &[char], symbols: &[PatternSymbol]) -> String {
+    let mut text = String::new();
+    for _ in 0..rng.random_range(1..=4) {
+        for _ in 0..rng.random_range(0..20) {
+            text.push(alphabet[rng.random_range(0..alphabet.len())]);
+        }
+        for s in symbols {
+            text.push(match s {
+                PatternSymbol::Char(c) => *c,
+                PatternSymbol::Class(cs) => cs[rng.random_range(0..cs.len())],
+                PatternSymbol::Any => alphabet[rng.random_range(0..alphabet.len())],
+            });
+        }
+    }
+    text
+}
+
+#[test]
+fn parse_and_iupac() {
+    let sa = ShiftAnd::parse("a[bą].\\.").unwrap();
+    assert_eq!(sa.m(), 4);
+    assert_eq!(sa.find_iter("xaąz.ab..").collect::<Vec<_>>(), [1, 6]);
+    for bad in ["a[bc", "a[]", "ab\\"] {
+        assert!(matches!(
+            ShiftAnd::parse(bad),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+    let sa = ShiftAnd::iupac("GRN").unwrap();
+    assert_eq!(sa.find_iter("GATGGCGAA").collect::<Vec<_>>(), [0, 3, 6]);
+    assert_eq!(
+        ShiftAnd::iupac("GXA").unwrap_err(),
+        Error::InvalidSymbol('X')
+    );
+    // Pozycja dowolna nie pasuje do niepoprawnego bajtu UTF-8.
+    let sa = ShiftAnd::parse("a.").unwrap();
+    assert_eq!(
+        sa.find_iter(&[b'a', 0xFF, b'a', b'b'][..])
+            .collect::<Vec<_>>(),
+        [2]
+    );
+}
+
+#[test]
+fn short_patterns_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(22);
+    let alphabet = ['a', 'b', 'ą'];
+    for _ in 0..500 {
+        let len = rng.random_range(0..=6);
+        let symbols = random_symbols(&mut rng, &alphabet, len);
+        let text = random_text(&mut rng, &alphabet, &symbols);
+        let sa = ShiftAnd::new(&symbols);
+        assert_eq!(
+            sa.find_iter(&text).collect::<Vec<_>>(),
+            brute_force(&text, &symbols),
+            "{symbols:?} w {text:?}"
+        );
+    }
+}
+
+#[test]
+fn multi_word_patterns_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(23);
+    let alphabet = ['a', 'b', 'ż'];
+    for len in [63, 64, 65, 127, 128, 129, 200] {
+        for _ in 0..20 {
+            let symbols = random_symbols(&mut rng, &alphabet, len);
+            let text = random_text(&mut rng, &alphabet, &symbols);
+            let want = brute_force(&text, &symbols);
+            assert!(!want.is_empty());
+            let sa = ShiftAnd::new(&symbols);
+            assert_eq!(sa.m(), len);
+            assert_eq!(sa.find_iter(&text).collect::<Vec<_>>(), want, "m = {len}");
+        }
+    }
+    // Okresowy tekst: wystąpienia nakładają się na granicach słów `u64`.
+    let pattern = "ab".repeat(50);
+    let text = "ab".repeat(120);
+    let sa = ShiftAnd::compile(&pattern);
+    let want: Vec<usize> = (0..=text.len() - pattern.len()).step_by(2).collect();
+    assert_eq!(sa.find_iter(&text).collect::<Vec<_>>(), want);
+}
@@ -0,0 +1,102 @@
+use std::io::{self, Read};
+
+use algorithmics::strings::{StreamAlgorithm, StreamMatches, stream_find_iter};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const ALGORITHMS: [StreamAlgorithm; 2] = [StreamAlgorithm::Automaton, StreamAlgorithm::RabinKarp];
+
+fn brute_force(text: &str, pattern: &str) -> Vec<u64> {
+    (0..=text.len())
+        .filter(|&i| text.is_char_boundary(i) && text[i..].starts_with(pattern))
+        .map(|i| i as u64)
+        .collect()
+}
+
+fn collect<R: Read>(matches: StreamMatches<R>) -> Vec<u64> {
+    matches.map(Result::unwrap).collect()
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[char], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn every_chunk_size_matches_brute_force() {
+    let mut rng = StdRng::seed_from_u64(8);
+    let alphabet = ['a', 'b', 'ą'];
+    for _ in 0..100 {
+        let pattern = random_word(&mut rng, &alphabet, 4);
+        let text = random_word(&mut rng, &alphabet, 25);
+        let want = brute_force(&text, &pattern);
+        for chunk_size in 1..=text.len() + 1 {
+            for algorithm in ALGORITHMS {
+                let matches = StreamMatches::new(text.as_bytes(), &pattern, algorithm)
+                    .with_chunk_size(chunk_size);
+                assert_eq!(
+                    collect(matches),
+                    want,
+                    "{algorithm:?} {pattern:?} w {text:?}, porcje {chunk_size}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn chunk_size_can_change_mid_iteration() {
+    let text = "abracadabra abracadabra";
+    let want = brute_force(text, "abra");
+    for algorithm in ALGORITHMS {
+        for before in 1..want.len() {
+            let mut matches = StreamMatches::new(text.as_bytes(), "abra", algorithm);
+            let mut got: Vec<u64> = matches.by_ref().take(before).map(Result::unwrap).collect();
+            got.extend(collect(matches.with_chunk_size(1)));
+            assert_eq!(got, want, "{algorithm:?}, zmiana po {before}");
+        }
+    }
+}
+
+#[test]
+fn position_and_end_of_stream() {
+    let mut matches = stream_find_iter("xaax".as_bytes(), "aa").with_chunk_size(2);
+    assert_eq!(matches.next().unwrap().unwrap(), 1);
+    assert_eq!(matches.position(), 3);
+    assert!(matches.next().is_none());
+    assert_eq!(matches.position(), 4);
+    assert_eq!(collect(stream_find_iter("aą".as_bytes(), "")), [0, 1, 3]);
+}
+
+/// Strumień zwracający błąd po wczytaniu `ok` bajtów.
+struct Failing<'a> {
+    data: &'a [u8],
+    ok: usize,
+}
+
+impl Read for Failing<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.ok == 0 {
+            return Err(io::Error::other("awaria"));
+        }
+        let n = buf.len().min(self.ok).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        self.ok -= n;
+        Ok(n)
+    }
+}
+
+#[test]
+fn read_error_ends_iteration() {
+    let reader = Failing {
+        data: b"abab",
+        ok: 3,
+    };
+    let mut matches = stream_find_iter(reader, "ab").with_chunk_size(1);
+    assert_eq!(matches.next().unwrap().unwrap(), 0);
+    assert!(matches.next().unwrap().is_err());
+    assert!(matches.next().is_none());
+}
@@ -0,0 +1,69 @@
+use algorithmics::dna::parse_sequence;
+use algorithmics::strings::{SuffixArray, lcp_array, suffix_array};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Tablica sufiksowa przez posortowanie wszystkich sufiksów.
+fn brute_force_sa(text: &[u8]) -> Vec<usize> {
+    let mut sa: Vec<usize> = (0..text.len()).collect();
+    sa.sort_by_key(|&i| &text[i..]);
+    sa
+}
+
+/// Tablica LCP przez porównanie sąsiednich sufiksów symbol po symbolu.
+fn brute_force_lcp(text: &[u8], sa: &[usize]) -> Vec<usize> {
+    let mut lcp = vec![0; sa.len()];
+    for w in 1..sa.len() {
+        lcp[w] = text[sa[w - 1]..]
+            .iter()
+            .zip(&text[sa[w]..])
+            .take_while(|(a, b)| a == b)
+            .count();
+    }
+    lcp
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[u8], max_len: usize) -> Vec<u8> {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_arrays() {
+    let sa = suffix_array(b"banana");
+    assert_eq!(sa, [5, 3, 1, 0, 4, 2]);
+    assert_eq!(lcp_array(b"banana", &sa), [0, 1, 3, 0, 0, 2]);
+    assert!(suffix_array::<u8>(&[]).is_empty());
+
+    let genome = parse_sequence("ACGTACGA").unwrap();
+    let index = SuffixArray::new(&genome);
+    assert_eq!(index.find_all(&parse_sequence("ACG").unwrap()), [0, 4]);
+    assert_eq!(index.count(&parse_sequence("T").unwrap()), 1);
+    assert_eq!(index.count(&[]), genome.len() + 1);
+}
+
+#[test]
+fn random_texts_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(18);
+    for alphabet in [&b"a"[..], &b"ab"[..], &b"abcd"[..]] {
+        for _ in 0..300 {
+            let text = random_word(&mut rng, alphabet, 50);
+            let index = SuffixArray::new(&text);
+            let sa = brute_force_sa(&text);
+            assert_eq!(index.suffix_array(), sa, "{text:?}");
+            assert_eq!(index.lcp(), brute_force_lcp(&text, &sa), "{text:?}");
+            assert_eq!(lcp_array(&text, &sa), index.lcp());
+            assert_eq!(index.text(), text);
+            for _ in 0..5 {
+                let pattern = random_word(&mut rng, alphabet, 4);
+                let want: Vec<usize> = (0..=text.len())
+                    .filter(|&i| text[i..].starts_with(&pattern))
+                    .collect();
+                assert_eq!(index.find_all(&pattern), want, "{pattern:?} w {text:?}");
+                assert_eq!(index.count(&pattern), want.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use algorithmics::strings::SuffixAutomaton;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Wszystkie niepuste podsłowa `text`.
+fn substrings(text: &str) -> HashSet<&str> {
+    let bounds: Vec<usize> = (0..=text.len())
+        .filter(|&i| text.is_char_boundary(i))
+        .collect();
+    let mut wynik = HashSet::new();
+    for (k, &i) in bounds.iter().enumerate() {
+        for &j in &bounds[k + 1..] {
+            wynik.insert(&text[i..j]);
+        }
+    }
+    wynik
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[char], max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_automaton() {
+    let sam = SuffixAutomaton::new("abcbc");
+    assert_eq!(sam.distinct_substrings(), 12);
+    assert!(sam.contains("cbc"));
+    assert!(!sam.contains("ca"));
+    assert_eq!(sam.first_occurrence("bc"), Some(1));
+    assert_eq!(sam.first_occurrence(""), Some(0));
+
+    let sam = SuffixAutomaton::new("żółw i żaba");
+    let common = sam.longest_common_substring("mały żółwik").unwrap();
+    assert_eq!(common.substring, "żółw");
+    assert_eq!(common.positions, [0, 6]);
+    assert!(sam.longest_common_substring("xyz").is_none());
+}
+
+#[test]
+fn random_texts_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(20);
+    let alphabet = ['a', 'b', 'ą'];
+    for _ in 0..300 {
+        let text = random_word(&mut rng, &alphabet, 15);
+        // Budowa przyrostowa daje ten sam automat co `new`.
+        let mut sam = SuffixAutomaton::default();
+        text.chars().for_each(|c| sam.push(c));
+        let all = substrings(&text);
+        assert_eq!(sam.distinct_substrings(), all.len(), "{text:?}");
+        let n = text.chars().count();
+        // Co najwyżej 2n - 1 stanów dla n ≥ 2.
+        assert!(sam.states() < (2 * n).max(n + 2), "{text:?}");
+        assert_eq!(sam.states(), SuffixAutomaton::new(&text).states());
+
+        for _ in 0..20 {
+            let pattern = random_word(&mut rng, &alphabet, 4);
+            assert_eq!(sam.contains(&pattern), text.contains(&pattern));
+            assert_eq!(sam.first_occurrence(&pattern), text.find(&pattern));
+        }
+
+        let other = random_word(&mut rng, &alphabet, 15);
+        let longest = substrings(&other)
+            .into_iter()
+            .filter(|s| all.contains(s))
+            .map(|s| s.chars().count())
+            .max();
+        match sam.longest_common_substring(&other) {
+            None => assert_eq!(longest, None, "{text:?} {other:?}"),
+            Some(common) => {
+                assert_eq!(Some(common.substring.chars().count()), longest);
+                assert_eq!(
+                    common.positions,
+                    [
+                        text.find(&common.substring).unwrap(),
+                        other.find(&common.substring).unwrap()
+                    ],
+                    "{text:?} {other:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use algorithmics::dna::parse_sequence;
+use algorithmics::strings::{z_array, z_search};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn brute_force_z(s: &[u8]) -> Vec<usize> {
+    (0..s.len())
+        .map(|i| s[i..].iter().zip(s).take_while(|(a, b)| a == b).count())
+        .collect()
+}
+
+fn brute_force_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&i| text[i..].starts_with(pattern))
+        .collect()
+}
+
+fn random_word(rng: &mut StdRng, alphabet: &[u8], max_len: usize) -> Vec<u8> {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+        .collect()
+}
+
+#[test]
+fn known_z_arrays() {
+    assert_eq!(
+        z_array(b"aabcaabxaaaz"),
+        [12, 1, 0, 0, 3, 1, 0, 0, 2, 2, 1, 0]
+    );
+    assert_eq!(z_array(b"aaaaa"), [5, 4, 3, 2, 1]);
+    assert!(z_array::<u8>(&[]).is_empty());
+    assert_eq!(z_search(b"abc", b""), [0, 1, 2, 3]);
+    let genome = parse_sequence("GATTACAGATTA").unwrap();
+    assert_eq!(z_search(&genome, &parse_sequence("GATTA").unwrap()), [0, 7]);
+}
+
+#[test]
+fn random_words_match_brute_force() {
+    let mut rng = StdRng::seed_from_u64(17);
+    for alphabet in [&b"ab"[..], &b"abcd"[..]] {
+        for _ in 0..1000 {
+            let text = random_word(&mut rng, alphabet, 40);
+            let pattern = random_word(&mut rng, alphabet, 5);
+            assert_eq!(z_array(&text), brute_force_z(&text), "{text:?}");
+            assert_eq!(
+                z_search(&text, &pattern),
+                brute_force_search(&text, &pattern),
+                "{pattern:?} w {text:?}"
+            );
+        }
+    }
+}